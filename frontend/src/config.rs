@@ -1,5 +1,8 @@
 use std::env;
 
+// Same-origin prefix that nginx (production) and Trunk (dev) proxy to the backend.
+pub const DEFAULT_API_BASE: &str = "/api";
+
 pub struct Config;
 
 impl Config {
@@ -18,4 +21,55 @@ impl Config {
         let port = Self::get_backend_port();
         format!("{}:{}", url, port)
     }
+
+    // Base for every browser API call. Relative by default so a deployed build
+    // works on any hostname; set `API_BASE_URL` (at build time for the wasm
+    // bundle) to talk to an absolute backend URL instead.
+    pub fn get_api_base_url() -> String {
+        env::var("API_BASE_URL")
+            .ok()
+            .or_else(|| option_env!("API_BASE_URL").map(str::to_string))
+            .filter(|url| !url.is_empty())
+            .map(|url| url.trim_end_matches('/').to_string())
+            .unwrap_or_else(|| DEFAULT_API_BASE.to_string())
+    }
+
+    // Review collections offered in the index selector, e.g.
+    // `REVIEW_INDEXES=production,staging,reviews-th`.
+    pub fn get_available_indexes() -> Vec<String> {
+        let configured = env::var("REVIEW_INDEXES")
+            .ok()
+            .or_else(|| option_env!("REVIEW_INDEXES").map(str::to_string))
+            .unwrap_or_default();
+        let indexes: Vec<String> = configured
+            .split(',')
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .collect();
+        if indexes.is_empty() {
+            vec![crate::models::DEFAULT_INDEX.to_string()]
+        } else {
+            indexes
+        }
+    }
+
+    // Like the other browser settings, `CLIENT_ERRORS_URL` has to be set at
+    // build time to reach the wasm bundle.
+    pub fn get_client_errors_endpoint() -> String {
+        env::var("CLIENT_ERRORS_URL")
+            .ok()
+            .or_else(|| option_env!("CLIENT_ERRORS_URL").map(str::to_string))
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| format!("{}/client-errors", Self::get_api_base_url()))
+    }
+
+    // Unset by default, so timings are only kept in memory for the telemetry
+    // panel. The wasm bundle has no process environment, so set
+    // `TELEMETRY_ENDPOINT` at build time.
+    pub fn get_telemetry_endpoint() -> Option<String> {
+        env::var("TELEMETRY_ENDPOINT")
+            .ok()
+            .or_else(|| option_env!("TELEMETRY_ENDPOINT").map(str::to_string))
+            .filter(|url| !url.is_empty())
+    }
 }
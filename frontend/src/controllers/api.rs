@@ -1,47 +1,327 @@
+use std::cell::RefCell;
 use crate::models::*;
 use crate::config::Config;
+use super::auth::with_auth;
+use super::cache::{Cached, LruCache, SearchCacheKey};
+use super::retry::{is_aborted, with_retry, ApiError, RetryPolicy};
+#[cfg(feature = "hydrate")]
+use super::server_fns::{check_health_server, search_reviews_server, submit_review_server};
+use super::telemetry::{perf_now_ms, record_search_timing, SearchTiming};
+use web_sys::AbortSignal;
 
-pub async fn submit_review(review: ReviewInput) -> Result<String, String> {
-    let backend_url = Config::get_full_backend_url();
+const SEARCH_CACHE_CAPACITY: usize = 20;
+const SEARCH_CACHE_TTL_MS: f64 = 5.0 * 60.0 * 1000.0;
+const PRODUCT_SUGGESTION_LIMIT: usize = 8;
+const QUERY_SUGGESTION_LIMIT: usize = 8;
+
+thread_local! {
+    static SEARCH_CACHE: RefCell<LruCache<SearchCacheKey, SearchResponse>> =
+        RefCell::new(LruCache::new(SEARCH_CACHE_CAPACITY, SEARCH_CACHE_TTL_MS));
+}
+
+// New or edited reviews can change any ranking, so drop everything.
+pub fn invalidate_search_cache() {
+    SEARCH_CACHE.with(|cache| cache.borrow_mut().clear());
+}
+
+pub async fn submit_review(review: ReviewInput) -> Result<String, ApiError> {
+    post_review(&review).await?;
+    invalidate_search_cache();
+    Ok("Review submitted successfully".to_string())
+}
+
+#[cfg(not(feature = "hydrate"))]
+async fn post_review(review: &ReviewInput) -> Result<(), ApiError> {
+    let backend_url = Config::get_api_base_url();
     let url = format!("{}/reviews", backend_url);
 
-    let response = gloo_net::http::Request::post(&url)
-        .json(&review)
+    let response = with_auth(gloo_net::http::Request::post(&url))
+        .json(review)
         .map_err(|e| format!("Failed to serialize: {}", e))?
         .send()
         .await
         .map_err(|e| format!("Request failed: {}", e))?;
 
     if response.ok() {
-        Ok("Review submitted successfully".to_string())
+        Ok(())
     } else {
-        let status = response.status();
-        let error_text = response.text().await.unwrap_or_default();
-        Err(format!("Failed with status {}: {}", status, error_text))
+        Err(ApiError::from_response(response).await)
     }
 }
 
-pub async fn search_reviews(query: String, k: usize) -> Result<SearchResponse, String> {
-    let backend_url = Config::get_full_backend_url();
-    let url = format!("{}/reviews/search", backend_url);
+// In the SSR build the frontend server proxies the backend for us.
+#[cfg(feature = "hydrate")]
+async fn post_review(review: &ReviewInput) -> Result<(), ApiError> {
+    let access_token = super::auth::load_session().map(|s| s.access_token);
+    submit_review_server(review.clone(), access_token)
+        .await
+        .map_err(ApiError::from)
+}
+
+// An aborted fetch fails like a dropped connection; this tells them apart.
+#[cfg(not(feature = "hydrate"))]
+fn fetch_error(signal: Option<&AbortSignal>, message: String) -> ApiError {
+    if is_aborted(signal) {
+        ApiError::Cancelled
+    } else {
+        ApiError::Other(message)
+    }
+}
 
-    let search_query = SearchQuery { query, k };
+#[cfg(not(feature = "hydrate"))]
+async fn send_search(search_query: &SearchQuery, signal: Option<&AbortSignal>) -> Result<SearchResponse, ApiError> {
+    let backend_url = Config::get_api_base_url();
+    let url = format!("{}/reviews/search", backend_url);
 
-    let response = gloo_net::http::Request::post(&url)
-        .json(&search_query)
+    let started_at = js_sys::Date::now();
+    let network_start = perf_now_ms();
+    let response = with_auth(gloo_net::http::Request::post(&url))
+        .abort_signal(signal)
+        .json(search_query)
         .map_err(|e| format!("Failed to serialize: {}", e))?
         .send()
         .await
+        .map_err(|e| fetch_error(signal, format!("Request failed: {}", e)))?;
+
+    if !response.ok() {
+        return Err(ApiError::from_response(response).await);
+    }
+
+    // Read the body as text first so download and decode time can be told apart.
+    let body = response
+        .text()
+        .await
+        .map_err(|e| fetch_error(signal, format!("Failed to read response: {}", e)))?;
+    let decode_start = perf_now_ms();
+    let parsed = serde_json::from_str::<SearchResponse>(&body)
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    record_search_timing(SearchTiming {
+        started_at,
+        network_ms: decode_start - network_start,
+        decode_ms: perf_now_ms() - decode_start,
+        render_ms: None,
+        result_count: parsed.results.len(),
+    });
+    Ok(parsed)
+}
+
+// Server function calls can't take an abort signal, so a cancelled search
+// runs to completion here and its response is dropped instead.
+#[cfg(feature = "hydrate")]
+async fn send_search(search_query: &SearchQuery, signal: Option<&AbortSignal>) -> Result<SearchResponse, ApiError> {
+    let started_at = js_sys::Date::now();
+    let network_start = perf_now_ms();
+    let access_token = super::auth::load_session().map(|s| s.access_token);
+    let parsed = search_reviews_server(search_query.clone(), access_token)
+        .await
+        .map_err(ApiError::from)?;
+    if is_aborted(signal) {
+        return Err(ApiError::Cancelled);
+    }
+
+    // The server function decodes as part of the call, so it all counts as network time.
+    record_search_timing(SearchTiming {
+        started_at,
+        network_ms: perf_now_ms() - network_start,
+        decode_ms: 0.0,
+        render_ms: None,
+        result_count: parsed.results.len(),
+    });
+    Ok(parsed)
+}
+
+pub async fn search_reviews(query: String, k: usize, index: String) -> Result<SearchResponse, ApiError> {
+    search_reviews_with_retry(query, k, index, None, |_| {}).await
+}
+
+// Searches are idempotent, so 429/503 responses are retried transparently;
+// `on_wait` receives each backoff delay (ms) so the UI can show a countdown.
+// Aborting `signal` cancels the request in flight and any retries left.
+pub async fn search_reviews_with_retry(
+    query: String,
+    k: usize,
+    index: String,
+    signal: Option<&AbortSignal>,
+    on_wait: impl Fn(u64),
+) -> Result<SearchResponse, ApiError> {
+    let search_query = SearchQuery { query, k, index };
+    with_retry(RetryPolicy::default(), signal, || send_search(&search_query, signal), on_wait).await
+}
+
+pub async fn search_reviews_cached(
+    query: String,
+    k: usize,
+    index: String,
+    force_refresh: bool,
+    signal: Option<&AbortSignal>,
+    on_wait: impl Fn(u64),
+) -> Result<Cached<SearchResponse>, ApiError> {
+    let key = SearchCacheKey::new(&query, k, &index);
+    if !force_refresh {
+        let hit = SEARCH_CACHE.with(|cache| cache.borrow_mut().get(&key, js_sys::Date::now()));
+        if let Some(value) = hit {
+            return Ok(Cached { value, from_cache: true });
+        }
+    }
+
+    let value = search_reviews_with_retry(query, k, index, signal, on_wait).await?;
+    // The user gave up on this search, so it shouldn't answer the next one.
+    if is_aborted(signal) {
+        return Err(ApiError::Cancelled);
+    }
+    SEARCH_CACHE.with(|cache| cache.borrow_mut().insert(key, value.clone(), js_sys::Date::now()));
+    Ok(Cached { value, from_cache: false })
+}
+
+pub async fn get_product_reviews(product_id: String, index: String) -> Result<Vec<Review>, ApiError> {
+    let backend_url = Config::get_api_base_url();
+    let url = format!("{}/reviews", backend_url);
+
+    let response = with_auth(gloo_net::http::Request::get(&url))
+        .query([("product_id", product_id.as_str()), ("index", index.as_str())])
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if response.ok() {
+        Ok(response
+            .json::<Vec<Review>>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?)
+    } else {
+        Err(ApiError::from_response(response).await)
+    }
+}
+
+// Existing products whose ID starts with `prefix`, most reviewed first. An
+// empty prefix lists the most reviewed products overall.
+pub async fn suggest_products(prefix: String, index: String) -> Result<Vec<ProductSummary>, ApiError> {
+    let backend_url = Config::get_api_base_url();
+    let url = format!("{}/products", backend_url);
+    let limit = PRODUCT_SUGGESTION_LIMIT.to_string();
+
+    let response = with_auth(gloo_net::http::Request::get(&url))
+        .query([("prefix", prefix.as_str()), ("index", index.as_str()), ("limit", limit.as_str())])
+        .send()
+        .await
         .map_err(|e| format!("Request failed: {}", e))?;
 
     if response.ok() {
-        response
-            .json::<SearchResponse>()
+        Ok(response
+            .json::<Vec<ProductSummary>>()
             .await
-            .map_err(|e| format!("Failed to parse response: {}", e))
+            .map_err(|e| format!("Failed to parse response: {}", e))?)
     } else {
-        let status = response.status();
-        let error_text = response.text().await.unwrap_or_default();
-        Err(format!("Search failed with status {}: {}", status, error_text))
+        Err(ApiError::from_response(response).await)
     }
-}
\ No newline at end of file
+}
+
+// Queries other users often run that start with `prefix`, most popular first.
+pub async fn popular_queries(prefix: String, index: String) -> Result<Vec<PopularQuery>, ApiError> {
+    let backend_url = Config::get_api_base_url();
+    let url = format!("{}/queries/popular", backend_url);
+    let limit = QUERY_SUGGESTION_LIMIT.to_string();
+
+    let response = with_auth(gloo_net::http::Request::get(&url))
+        .query([("prefix", prefix.as_str()), ("index", index.as_str()), ("limit", limit.as_str())])
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if response.ok() {
+        Ok(response
+            .json::<Vec<PopularQuery>>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?)
+    } else {
+        Err(ApiError::from_response(response).await)
+    }
+}
+
+#[cfg(feature = "hydrate")]
+pub async fn check_health() -> Result<HealthResponse, String> {
+    check_health_server().await.map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "hydrate"))]
+pub async fn check_health() -> Result<HealthResponse, String> {
+    let backend_url = Config::get_api_base_url();
+    let url = format!("{}/health", backend_url);
+
+    let response = with_auth(gloo_net::http::Request::get(&url))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if !response.ok() {
+        // The backend is listening but unhappy; report it rather than failing.
+        return Ok(HealthResponse {
+            status: format!("HTTP {}", response.status()),
+            ..Default::default()
+        });
+    }
+
+    // A 2xx with a plain-text or status-less body still means the backend is up.
+    let text = response.text().await.unwrap_or_default();
+    let mut health = serde_json::from_str::<HealthResponse>(&text).unwrap_or_default();
+    if health.status.is_empty() {
+        health.status = "ok".to_string();
+    }
+    Ok(health)
+}
+
+// Admin calls go straight to the backend in every build: they are rare,
+// operator-only, and never part of server rendering.
+async fn send_admin<T: serde::de::DeserializeOwned>(
+    request: gloo_net::http::RequestBuilder,
+) -> Result<T, ApiError> {
+    let response = with_auth(request)
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if response.ok() {
+        Ok(response
+            .json::<T>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?)
+    } else {
+        Err(ApiError::from_response(response).await)
+    }
+}
+
+fn admin_url(path: &str) -> String {
+    format!("{}/admin{}", Config::get_api_base_url(), path)
+}
+
+pub async fn get_index_stats(index: &str) -> Result<IndexStats, ApiError> {
+    send_admin(gloo_net::http::Request::get(&admin_url(&format!("/indexes/{}/stats", index)))).await
+}
+
+pub async fn list_snapshots(index: &str) -> Result<Vec<Snapshot>, ApiError> {
+    send_admin(gloo_net::http::Request::get(&admin_url(&format!("/indexes/{}/snapshots", index)))).await
+}
+
+// Each maintenance action starts a background job on the backend and returns
+// it immediately; poll `get_job` to follow its progress.
+pub async fn start_reindex(index: &str) -> Result<Job, ApiError> {
+    send_admin(gloo_net::http::Request::post(&admin_url(&format!("/indexes/{}/reindex", index)))).await
+}
+
+pub async fn start_compaction(index: &str) -> Result<Job, ApiError> {
+    send_admin(gloo_net::http::Request::post(&admin_url(&format!("/indexes/{}/compact", index)))).await
+}
+
+pub async fn create_snapshot(index: &str) -> Result<Job, ApiError> {
+    send_admin(gloo_net::http::Request::post(&admin_url(&format!("/indexes/{}/snapshots", index)))).await
+}
+
+pub async fn restore_snapshot(index: &str, snapshot_id: &str) -> Result<Job, ApiError> {
+    let path = format!("/indexes/{}/snapshots/{}/restore", index, snapshot_id);
+    send_admin(gloo_net::http::Request::post(&admin_url(&path))).await
+}
+
+pub async fn get_job(job_id: &str) -> Result<Job, ApiError> {
+    send_admin(gloo_net::http::Request::get(&admin_url(&format!("/jobs/{}", job_id)))).await
+}
@@ -0,0 +1,92 @@
+use gloo_net::http::RequestBuilder;
+use crate::config::Config;
+use crate::models::auth::{AuthSession, LoginRequest, RefreshRequest, TokenResponse};
+use super::storage::session_storage;
+
+// Tokens live in sessionStorage so they are scoped to the tab and dropped when
+// it closes, instead of lingering in localStorage.
+const SESSION_KEY: &str = "spfresh.auth";
+
+pub fn now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+pub fn load_session() -> Option<AuthSession> {
+    let raw = session_storage()?.get_item(SESSION_KEY).ok().flatten()?;
+    let session = serde_json::from_str::<AuthSession>(&raw).ok()?;
+    if session.is_expired(now_ms()) && session.refresh_token.is_none() {
+        clear_session();
+        return None;
+    }
+    Some(session)
+}
+
+pub fn store_session(session: &AuthSession) {
+    if let (Some(storage), Ok(raw)) = (session_storage(), serde_json::to_string(session)) {
+        let _ = storage.set_item(SESSION_KEY, &raw);
+    }
+}
+
+pub fn clear_session() {
+    if let Some(storage) = session_storage() {
+        let _ = storage.remove_item(SESSION_KEY);
+    }
+}
+
+pub fn with_auth(request: RequestBuilder) -> RequestBuilder {
+    match load_session() {
+        Some(session) if !session.is_expired(now_ms()) => {
+            request.header("Authorization", &format!("Bearer {}", session.access_token))
+        }
+        _ => request,
+    }
+}
+
+async fn request_token<T: serde::Serialize>(path: &str, body: &T) -> Result<TokenResponse, String> {
+    let backend_url = Config::get_api_base_url();
+    let url = format!("{}{}", backend_url, path);
+
+    let response = gloo_net::http::Request::post(&url)
+        .json(body)
+        .map_err(|e| format!("Failed to serialize: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if response.ok() {
+        response
+            .json::<TokenResponse>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    } else {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        Err(format!("Authentication failed with status {}: {}", status, error_text))
+    }
+}
+
+pub async fn login(username: String, password: String) -> Result<AuthSession, String> {
+    let token = request_token("/auth/login", &LoginRequest { username: username.clone(), password }).await?;
+    let session = AuthSession::from_token(username, token, now_ms());
+    store_session(&session);
+    Ok(session)
+}
+
+pub async fn refresh(session: &AuthSession) -> Result<AuthSession, String> {
+    let refresh_token = session
+        .refresh_token
+        .clone()
+        .ok_or_else(|| "Session cannot be refreshed".to_string())?;
+    let token = request_token("/auth/refresh", &RefreshRequest { refresh_token }).await?;
+
+    let mut refreshed = AuthSession::from_token(session.username.clone(), token, now_ms());
+    if refreshed.refresh_token.is_none() {
+        refreshed.refresh_token = session.refresh_token.clone();
+    }
+    store_session(&refreshed);
+    Ok(refreshed)
+}
+
+pub fn logout() {
+    clear_session();
+}
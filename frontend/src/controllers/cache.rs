@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct Cached<T> {
+    pub value: T,
+    pub from_cache: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchCacheKey {
+    pub query: String,
+    pub k: usize,
+    pub index: String,
+}
+
+impl SearchCacheKey {
+    // Queries differing only in case or whitespace hit the same entry.
+    pub fn new(query: &str, k: usize, index: &str) -> Self {
+        Self {
+            query: query.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase(),
+            k,
+            index: index.to_string(),
+        }
+    }
+}
+
+// Small, linear-scan LRU: the handful of entries we keep makes a hash map
+// plus linked list not worth it. Most recently used entries sit at the back.
+pub struct LruCache<K, V> {
+    capacity: usize,
+    ttl_ms: f64,
+    entries: VecDeque<(K, f64, V)>,
+}
+
+impl<K: PartialEq, V: Clone> LruCache<K, V> {
+    pub fn new(capacity: usize, ttl_ms: f64) -> Self {
+        Self {
+            capacity,
+            ttl_ms,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn get(&mut self, key: &K, now_ms: f64) -> Option<V> {
+        let ttl_ms = self.ttl_ms;
+        self.entries.retain(|(_, stored_at, _)| now_ms - stored_at < ttl_ms);
+
+        let pos = self.entries.iter().position(|(k, _, _)| k == key)?;
+        let entry = self.entries.remove(pos)?;
+        let value = entry.2.clone();
+        self.entries.push_back(entry);
+        Some(value)
+    }
+
+    pub fn insert(&mut self, key: K, value: V, now_ms: f64) {
+        self.entries.retain(|(k, _, _)| k != &key);
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, now_ms, value));
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_expire_after_the_ttl() {
+        let mut cache = LruCache::new(2, 100.0);
+        cache.insert("a", 1, 0.0);
+
+        assert_eq!(cache.get(&"a", 99.0), Some(1));
+        assert_eq!(cache.get(&"a", 100.0), None);
+    }
+
+    #[test]
+    fn least_recently_used_entry_is_evicted() {
+        let mut cache = LruCache::new(2, 1000.0);
+        cache.insert("a", 1, 0.0);
+        cache.insert("b", 2, 1.0);
+        cache.insert("c", 3, 2.0);
+
+        assert_eq!(cache.get(&"a", 3.0), None);
+        assert_eq!(cache.get(&"b", 3.0), Some(2));
+        assert_eq!(cache.get(&"c", 3.0), Some(3));
+    }
+
+    #[test]
+    fn hit_makes_an_entry_most_recent_without_extending_its_ttl() {
+        let mut cache = LruCache::new(2, 100.0);
+        cache.insert("a", 1, 0.0);
+        cache.insert("b", 2, 10.0);
+        assert_eq!(cache.get(&"a", 20.0), Some(1));
+        cache.insert("c", 3, 30.0);
+
+        assert_eq!(cache.get(&"b", 40.0), None);
+        assert_eq!(cache.get(&"a", 40.0), Some(1));
+        assert_eq!(cache.get(&"a", 100.0), None);
+    }
+
+    #[test]
+    fn reinserting_replaces_the_value_and_restarts_the_ttl() {
+        let mut cache = LruCache::new(2, 100.0);
+        cache.insert("a", 1, 0.0);
+        cache.insert("b", 2, 10.0);
+        cache.insert("a", 10, 50.0);
+
+        // Replacing "a" must not evict "b" to make room.
+        assert_eq!(cache.get(&"b", 60.0), Some(2));
+        assert_eq!(cache.get(&"a", 120.0), Some(10));
+    }
+
+    #[test]
+    fn keys_ignore_case_and_whitespace() {
+        assert_eq!(SearchCacheKey::new("  Great  Sound ", 5, "reviews"), SearchCacheKey::new("great sound", 5, "reviews"));
+        assert_ne!(SearchCacheKey::new("great sound", 5, "reviews"), SearchCacheKey::new("great sound", 10, "reviews"));
+    }
+}
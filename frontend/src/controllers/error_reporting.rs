@@ -0,0 +1,200 @@
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::panic;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use crate::config::Config;
+use super::storage::local_storage;
+
+const BUFFER_KEY: &str = "spfresh.client-errors";
+const MAX_BUFFERED: usize = 50;
+const DEDUP_WINDOW_MS: f64 = 60_000.0;
+const BUILD_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    Panic,
+    Api,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientErrorReport {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub route: String,
+    pub last_query: Option<String>,
+    pub build_version: String,
+    pub occurred_at: f64,
+}
+
+#[derive(Default)]
+struct ReporterState {
+    last_query: Option<String>,
+    // Fingerprint -> time it was last reported.
+    recent: HashMap<u64, f64>,
+}
+
+thread_local! {
+    static STATE: RefCell<ReporterState> = RefCell::new(ReporterState::default());
+}
+
+fn current_route() -> String {
+    web_sys::window()
+        .map(|w| {
+            let location = w.location();
+            format!(
+                "{}{}",
+                location.pathname().unwrap_or_default(),
+                location.search().unwrap_or_default()
+            )
+        })
+        .unwrap_or_default()
+}
+
+fn fingerprint(kind: ErrorKind, message: &str, route: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (kind as u8).hash(&mut hasher);
+    message.hash(&mut hasher);
+    route.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn is_online() -> bool {
+    web_sys::window().is_some_and(|w| w.navigator().on_line())
+}
+
+fn read_buffer() -> Vec<ClientErrorReport> {
+    local_storage()
+        .and_then(|s| s.get_item(BUFFER_KEY).ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn write_buffer(reports: &[ClientErrorReport]) {
+    let Some(storage) = local_storage() else { return };
+    if reports.is_empty() {
+        let _ = storage.remove_item(BUFFER_KEY);
+    } else if let Ok(raw) = serde_json::to_string(reports) {
+        let _ = storage.set_item(BUFFER_KEY, &raw);
+    }
+}
+
+fn buffer_reports(reports: Vec<ClientErrorReport>) {
+    let mut buffered = read_buffer();
+    buffered.extend(reports);
+    let overflow = buffered.len().saturating_sub(MAX_BUFFERED);
+    buffered.drain(..overflow);
+    write_buffer(&buffered);
+}
+
+pub fn set_last_query(query: &str) {
+    STATE.with(|s| s.borrow_mut().last_query = Some(query.to_string()));
+}
+
+fn build_report(kind: ErrorKind, message: String) -> Option<ClientErrorReport> {
+    let route = current_route();
+    let now = js_sys::Date::now();
+    let id = fingerprint(kind, &message, &route);
+
+    STATE.with(|s| {
+        let mut state = s.borrow_mut();
+        if state.recent.get(&id).is_some_and(|last| now - last < DEDUP_WINDOW_MS) {
+            return None;
+        }
+        // Expired fingerprints would never suppress anything again.
+        state.recent.retain(|_, last| now - *last < DEDUP_WINDOW_MS);
+        state.recent.insert(id, now);
+        Some(ClientErrorReport {
+            kind,
+            message,
+            route,
+            last_query: state.last_query.clone(),
+            build_version: BUILD_VERSION.to_string(),
+            occurred_at: now,
+        })
+    })
+}
+
+async fn post_reports(reports: &[ClientErrorReport]) -> Result<(), String> {
+    let response = gloo_net::http::Request::post(&Config::get_client_errors_endpoint())
+        .json(reports)
+        .map_err(|e| format!("Failed to serialize: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if response.ok() {
+        Ok(())
+    } else {
+        Err(format!("Error report rejected with status {}", response.status()))
+    }
+}
+
+pub async fn flush_buffered_reports() {
+    if !is_online() {
+        return;
+    }
+    let buffered = read_buffer();
+    if buffered.is_empty() {
+        return;
+    }
+    write_buffer(&[]);
+    if post_reports(&buffered).await.is_err() {
+        buffer_reports(buffered);
+    }
+}
+
+pub fn report_api_error(error: &impl std::fmt::Display) {
+    let Some(report) = build_report(ErrorKind::Api, error.to_string()) else { return };
+
+    if !is_online() {
+        buffer_reports(vec![report]);
+        return;
+    }
+    wasm_bindgen_futures::spawn_local(async move {
+        if post_reports(std::slice::from_ref(&report)).await.is_err() {
+            buffer_reports(vec![report]);
+            return;
+        }
+        flush_buffered_reports().await;
+    });
+}
+
+// The wasm instance is unusable once the panic hook returns, so async requests
+// would never complete. sendBeacon is queued synchronously and delivered by the
+// browser even after the module traps; if it can't be queued, buffer for later.
+fn report_panic(info: &panic::PanicHookInfo<'_>) {
+    let Some(report) = build_report(ErrorKind::Panic, info.to_string()) else { return };
+    let Ok(body) = serde_json::to_string(&[&report]) else { return };
+
+    let sent = is_online()
+        && web_sys::window().is_some_and(|w| {
+            w.navigator()
+                .send_beacon_with_opt_str(&Config::get_client_errors_endpoint(), Some(&body))
+                .unwrap_or(false)
+        });
+    if !sent {
+        buffer_reports(vec![report]);
+    }
+}
+
+pub fn install_error_reporting() {
+    panic::set_hook(Box::new(|info| {
+        console_error_panic_hook::hook(info);
+        report_panic(info);
+    }));
+
+    if let Some(window) = web_sys::window() {
+        let on_online = Closure::<dyn Fn()>::new(|| {
+            wasm_bindgen_futures::spawn_local(flush_buffered_reports());
+        });
+        let _ = window.add_event_listener_with_callback("online", on_online.as_ref().unchecked_ref());
+        // Lives for the whole page session.
+        on_online.forget();
+    }
+
+    wasm_bindgen_futures::spawn_local(flush_buffered_reports());
+}
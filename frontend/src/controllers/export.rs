@@ -0,0 +1,245 @@
+use std::time::Duration;
+use serde::Serialize;
+use wasm_bindgen::{JsCast, JsValue};
+use crate::models::search::SearchResult;
+
+// Revoking the object URL straight after `click()` can cancel the download in
+// some browsers, so it is kept alive for a while first.
+const REVOKE_URL_AFTER: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv;charset=utf-8",
+            ExportFormat::Json => "application/json",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ExportedResult<'a> {
+    rank: usize,
+    score: f32,
+    review_title: &'a str,
+    review_body: &'a str,
+    product_id: &'a str,
+    review_rating: i32,
+}
+
+#[derive(Serialize)]
+struct ExportedSearch<'a> {
+    query: &'a str,
+    k: usize,
+    results: Vec<ExportedResult<'a>>,
+}
+
+const CSV_HEADER: &str = "query,k,rank,score,review_title,review_body,product_id,review_rating";
+
+// Spreadsheets run a cell starting with one of these as a formula.
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+// RFC 4180: quote any field containing a delimiter, quote or line break,
+// doubling embedded quotes. Text that would be read as a formula gets a
+// leading `'` so it opens as plain text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(FORMULA_PREFIXES) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+// A result without a score is written as an empty cell.
+fn score_field(score: f32) -> String {
+    if score.is_finite() {
+        score.to_string()
+    } else {
+        String::new()
+    }
+}
+
+// `ranked` pairs each result with the (1-based) rank it is shown at.
+pub fn results_to_csv(query: &str, k: usize, ranked: &[(usize, &SearchResult)]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+
+    for &(rank, result) in ranked {
+        let row = [
+            csv_field(query),
+            k.to_string(),
+            rank.to_string(),
+            score_field(result.score),
+            csv_field(&result.review_title),
+            csv_field(&result.review_body),
+            csv_field(&result.product_id),
+            result.review_rating.to_string(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+pub fn results_to_json(query: &str, k: usize, ranked: &[(usize, &SearchResult)]) -> Result<String, String> {
+    let export = ExportedSearch {
+        query,
+        k,
+        results: ranked
+            .iter()
+            .map(|&(rank, r)| ExportedResult {
+                rank,
+                score: r.score,
+                review_title: &r.review_title,
+                review_body: &r.review_body,
+                product_id: &r.product_id,
+                review_rating: r.review_rating,
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&export).map_err(|e| format!("Failed to serialize: {}", e))
+}
+
+pub fn download_file(filename: &str, mime_type: &str, contents: &str) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| "No document available".to_string())?;
+
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)
+        .map_err(|e| format!("Failed to create file: {:?}", e))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(|e| format!("Failed to create download link: {:?}", e))?;
+
+    let anchor = document
+        .create_element("a")
+        .map_err(|e| format!("Failed to create download link: {:?}", e))?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|_| "Failed to create download link".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    leptos::set_timeout(
+        move || {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        },
+        REVOKE_URL_AFTER,
+    );
+    Ok(())
+}
+
+pub fn export_results(
+    format: ExportFormat,
+    query: &str,
+    k: usize,
+    ranked: &[(usize, &SearchResult)],
+) -> Result<(), String> {
+    let contents = match format {
+        ExportFormat::Csv => results_to_csv(query, k, ranked),
+        ExportFormat::Json => results_to_json(query, k, ranked)?,
+    };
+    let filename = format!("search-results.{}", format.extension());
+
+    download_file(&filename, format.mime_type(), &contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(title: &str, body: &str) -> SearchResult {
+        SearchResult {
+            review_title: title.to_string(),
+            review_body: body.to_string(),
+            product_id: "B001".to_string(),
+            review_rating: 4,
+            score: 0.5,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn plain_fields_are_not_quoted() {
+        assert_eq!(csv_field("battery life"), "battery life");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn fields_with_commas_are_quoted() {
+        assert_eq!(csv_field("cheap, but loud"), "\"cheap, but loud\"");
+    }
+
+    #[test]
+    fn embedded_quotes_are_doubled() {
+        assert_eq!(csv_field("the \"pro\" model"), "\"the \"\"pro\"\" model\"");
+        assert_eq!(csv_field("\"\""), "\"\"\"\"\"\"");
+    }
+
+    #[test]
+    fn line_breaks_are_quoted() {
+        assert_eq!(csv_field("line one\nline two"), "\"line one\nline two\"");
+        assert_eq!(csv_field("line one\r\nline two"), "\"line one\r\nline two\"");
+        assert_eq!(csv_field("carriage\rreturn"), "\"carriage\rreturn\"");
+    }
+
+    #[test]
+    fn formula_prefixes_are_escaped() {
+        assert_eq!(csv_field("=SUM(A1:A9)"), "'=SUM(A1:A9)");
+        assert_eq!(csv_field("+1 great"), "'+1 great");
+        assert_eq!(csv_field("-5 stars"), "'-5 stars");
+        assert_eq!(csv_field("@user"), "'@user");
+        assert_eq!(csv_field("\tindented"), "'\tindented");
+        assert_eq!(csv_field("\rstray"), "\"'\rstray\"");
+        assert_eq!(csv_field("=HYPERLINK(\"x\", \"y\")"), "\"'=HYPERLINK(\"\"x\"\", \"\"y\"\")\"");
+        assert_eq!(csv_field("a = b"), "a = b");
+    }
+
+    #[test]
+    fn rows_use_the_given_ranks() {
+        let first = result("Great, really", "Says \"wow\"\nTwice");
+        let second = result("Fine", "Okay");
+        let csv = results_to_csv("q", 10, &[(3, &first), (7, &second)]);
+
+        assert_eq!(
+            csv,
+            format!(
+                "{}\r\n{}\r\n{}\r\n",
+                CSV_HEADER,
+                "q,10,3,0.5,\"Great, really\",\"Says \"\"wow\"\"\nTwice\",B001,4",
+                "q,10,7,0.5,Fine,Okay,B001,4",
+            )
+        );
+    }
+
+    #[test]
+    fn json_uses_the_given_ranks() {
+        let only = result("Fine", "Okay");
+        let json = results_to_json("q", 1, &[(2, &only)]).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["results"][0]["rank"], 2);
+        assert_eq!(parsed["results"][0]["review_title"], "Fine");
+    }
+}
@@ -1,3 +1,23 @@
 pub mod api;
+pub mod auth;
+pub mod cache;
+pub mod error_reporting;
+pub mod export;
+pub mod retry;
+pub mod search_history;
+pub mod server_fns;
+pub mod storage;
+pub mod telemetry;
+pub mod transport;
 
-pub use api::*;
\ No newline at end of file
+pub use api::*;
+pub use auth::*;
+pub use cache::*;
+pub use error_reporting::*;
+pub use export::*;
+pub use retry::*;
+pub use search_history::*;
+pub use server_fns::*;
+pub use storage::*;
+pub use telemetry::*;
+pub use transport::*;
\ No newline at end of file
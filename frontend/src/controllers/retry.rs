@@ -0,0 +1,251 @@
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+use gloo_net::http::Response;
+use leptos::ServerFnError;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::AbortSignal;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    RateLimited { retry_after_ms: Option<u64> },
+    Unavailable { retry_after_ms: Option<u64> },
+    Http { status: u16, body: String },
+    // The caller aborted the request; there is nothing to show or report.
+    Cancelled,
+    Other(String),
+}
+
+impl ApiError {
+    pub async fn from_response(response: Response) -> Self {
+        let retry_after_ms = response
+            .headers()
+            .get("Retry-After")
+            .and_then(|value| parse_retry_after(&value, js_sys::Date::now()));
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Self::from_status(status, retry_after_ms, body)
+    }
+
+    pub fn from_status(status: u16, retry_after_ms: Option<u64>, body: String) -> Self {
+        match status {
+            429 => ApiError::RateLimited { retry_after_ms },
+            503 => ApiError::Unavailable { retry_after_ms },
+            status => ApiError::Http { status, body },
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ApiError::RateLimited { .. } | ApiError::Unavailable { .. })
+    }
+
+    pub fn retry_after_ms(&self) -> Option<u64> {
+        match self {
+            ApiError::RateLimited { retry_after_ms } | ApiError::Unavailable { retry_after_ms } => *retry_after_ms,
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::RateLimited { .. } => write!(f, "Too many requests, please slow down"),
+            ApiError::Unavailable { .. } => write!(f, "Backend is temporarily unavailable"),
+            ApiError::Http { status, body } => write!(f, "HTTP {}: {}", status, body),
+            ApiError::Cancelled => write!(f, "Request was cancelled"),
+            ApiError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<String> for ApiError {
+    fn from(msg: String) -> Self {
+        ApiError::Other(msg)
+    }
+}
+
+impl From<ServerFnError<UpstreamError>> for ApiError {
+    fn from(error: ServerFnError<UpstreamError>) -> Self {
+        match error {
+            ServerFnError::WrappedServerError(UpstreamError { status, retry_after_ms, body }) => {
+                Self::from_status(status, retry_after_ms, body)
+            }
+            other => ApiError::Other(other.to_string()),
+        }
+    }
+}
+
+// A non-2xx backend response seen by a server function, passed on to the
+// browser so it can tell a 429/503 worth retrying from any other failure.
+// Server functions carry custom errors as their `Display` output and read
+// them back with `FromStr`, so both are JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpstreamError {
+    pub status: u16,
+    pub retry_after_ms: Option<u64>,
+    pub body: String,
+}
+
+impl fmt::Display for UpstreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+impl FromStr for UpstreamError {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+pub fn is_aborted(signal: Option<&AbortSignal>) -> bool {
+    signal.is_some_and(AbortSignal::aborted)
+}
+
+// `Retry-After` is either a number of seconds or an HTTP date.
+pub fn parse_retry_after(value: &str, now_ms: f64) -> Option<u64> {
+    parse_retry_after_with(value, now_ms, |date| {
+        let at = js_sys::Date::parse(date);
+        (!at.is_nan()).then_some(at)
+    })
+}
+
+// `parse_date` turns an HTTP date into milliseconds since the Unix epoch;
+// the server has no `Date.parse` to lean on.
+pub fn parse_retry_after_with(value: &str, now_ms: f64, parse_date: impl FnOnce(&str) -> Option<f64>) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds.saturating_mul(1000));
+    }
+    let at = parse_date(value)?;
+    Some((at - now_ms).max(0.0) as u64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay_ms: 500,
+            max_delay_ms: 10_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    // A server-provided `Retry-After` wins; otherwise back off exponentially
+    // with "equal jitter" so a burst of clients doesn't retry in lockstep.
+    pub fn delay_for(&self, attempt: u32, retry_after_ms: Option<u64>) -> u64 {
+        if let Some(ms) = retry_after_ms {
+            return ms;
+        }
+        let exp = self
+            .base_delay_ms
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.max_delay_ms);
+        let half = exp / 2;
+        half + (js_sys::Math::random() * half as f64) as u64
+    }
+}
+
+pub async fn sleep_ms(ms: u64) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                resolve.unchecked_ref(),
+                ms.min(i32::MAX as u64) as i32,
+            );
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+// Only wrap idempotent requests: the operation may run several times. Once
+// `signal` is aborted no further attempt is made, even mid-backoff.
+pub async fn with_retry<T, F, Fut>(
+    policy: RetryPolicy,
+    signal: Option<&AbortSignal>,
+    mut operation: F,
+    on_wait: impl Fn(u64),
+) -> Result<T, ApiError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ApiError>>,
+{
+    let mut attempt = 0;
+    loop {
+        if is_aborted(signal) {
+            return Err(ApiError::Cancelled);
+        }
+        match operation().await {
+            Err(e) if e.is_retryable() && attempt + 1 < policy.max_attempts => {
+                let delay = policy.delay_for(attempt, e.retry_after_ms());
+                if delay > policy.max_delay_ms * 3 {
+                    // Waiting this long silently is worse than telling the user.
+                    return Err(e);
+                }
+                on_wait(delay);
+                sleep_ms(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::server_fn::error::ServerFnErrorSerde;
+
+    fn no_date(_: &str) -> Option<f64> {
+        None
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        assert_eq!(parse_retry_after_with(" 3 ", 0.0, no_date), Some(3_000));
+        assert_eq!(parse_retry_after_with(&u64::MAX.to_string(), 0.0, no_date), Some(u64::MAX));
+    }
+
+    #[test]
+    fn retry_after_date() {
+        assert_eq!(parse_retry_after_with("later", 1_000.0, |_| Some(4_500.0)), Some(3_500));
+        assert_eq!(parse_retry_after_with("earlier", 1_000.0, |_| Some(0.0)), Some(0));
+        assert_eq!(parse_retry_after_with("garbage", 1_000.0, no_date), None);
+    }
+
+    #[test]
+    fn only_429_and_503_are_retryable() {
+        assert_eq!(
+            ApiError::from_status(429, Some(2_000), String::new()),
+            ApiError::RateLimited { retry_after_ms: Some(2_000) }
+        );
+        assert!(ApiError::from_status(503, None, String::new()).is_retryable());
+        assert!(!ApiError::from_status(500, None, "boom".into()).is_retryable());
+    }
+
+    #[test]
+    fn upstream_error_survives_the_wire() {
+        let upstream = UpstreamError { status: 429, retry_after_ms: Some(2_000), body: "slow down".into() };
+        let error: ServerFnError<UpstreamError> = upstream.clone().into();
+        let decoded = ServerFnError::<UpstreamError>::de(&error.ser().unwrap());
+        assert_eq!(ApiError::from(decoded), ApiError::RateLimited { retry_after_ms: Some(2_000) });
+        assert_eq!(
+            ApiError::from_status(500, None, "boom".into()),
+            ApiError::Http { status: 500, body: "boom".into() }
+        );
+    }
+}
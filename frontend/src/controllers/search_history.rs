@@ -0,0 +1,54 @@
+use super::storage::local_storage;
+
+const HISTORY_KEY: &str = "spfresh.search_history";
+const SAVED_KEY: &str = "spfresh.saved_searches";
+const HISTORY_LIMIT: usize = 20;
+
+fn load_list(key: &str) -> Vec<String> {
+    local_storage()
+        .and_then(|s| s.get_item(key).ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn store_list(key: &str, list: &[String]) {
+    if let (Some(storage), Ok(raw)) = (local_storage(), serde_json::to_string(list)) {
+        let _ = storage.set_item(key, &raw);
+    }
+}
+
+// Most recent first.
+pub fn load_history() -> Vec<String> {
+    load_list(HISTORY_KEY)
+}
+
+// Moves `query` to the front of the history and returns the updated list.
+pub fn record_history(query: &str) -> Vec<String> {
+    let query = query.trim();
+    let mut history = load_history();
+    if query.is_empty() {
+        return history;
+    }
+    history.retain(|q| q != query);
+    history.insert(0, query.to_string());
+    history.truncate(HISTORY_LIMIT);
+    store_list(HISTORY_KEY, &history);
+    history
+}
+
+pub fn load_saved_searches() -> Vec<String> {
+    load_list(SAVED_KEY)
+}
+
+// Saves `query`, or forgets it if it was already saved, and returns the updated list.
+pub fn toggle_saved_search(query: &str) -> Vec<String> {
+    let query = query.trim();
+    let mut saved = load_saved_searches();
+    if saved.iter().any(|q| q == query) {
+        saved.retain(|q| q != query);
+    } else if !query.is_empty() {
+        saved.push(query.to_string());
+    }
+    store_list(SAVED_KEY, &saved);
+    saved
+}
@@ -0,0 +1,48 @@
+use leptos::*;
+use crate::models::*;
+use super::retry::UpstreamError;
+
+// Server functions proxy the SPFresh backend through the frontend server in
+// SSR mode: the browser only ever talks to its own origin, so the backend
+// address stays private and no CORS setup is needed.
+
+// Backend failures come back as `UpstreamError`, keeping the status and
+// `Retry-After` so the browser can retry rate-limited searches.
+#[server(SearchReviewsServer, "/rpc")]
+pub async fn search_reviews_server(
+    search: SearchQuery,
+    access_token: Option<String>,
+) -> Result<SearchResponse, ServerFnError<UpstreamError>> {
+    use super::transport::server::post_json;
+
+    post_json("/reviews/search", &search, access_token.as_deref()).await
+}
+
+#[server(SubmitReviewServer, "/rpc")]
+pub async fn submit_review_server(
+    review: ReviewInput,
+    access_token: Option<String>,
+) -> Result<(), ServerFnError<UpstreamError>> {
+    use super::transport::server::post;
+
+    post("/reviews", &review, access_token.as_deref()).await
+}
+
+#[server(CheckHealthServer, "/rpc")]
+pub async fn check_health_server() -> Result<HealthResponse, ServerFnError> {
+    use super::transport::server::get_with_status;
+
+    let (status, text) = get_with_status("/health").await.map_err(ServerFnError::<server_fn::error::NoCustomError>::ServerError)?;
+    if !(200..300).contains(&status) {
+        return Ok(HealthResponse {
+            status: format!("HTTP {}", status),
+            ..Default::default()
+        });
+    }
+
+    let mut health = serde_json::from_str::<HealthResponse>(&text).unwrap_or_default();
+    if health.status.is_empty() {
+        health.status = "ok".to_string();
+    }
+    Ok(health)
+}
@@ -0,0 +1,9 @@
+// Web storage is missing during server rendering and can be disabled by the
+// user (e.g. private browsing), so callers treat `None` as "don't persist".
+pub fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+}
+
+pub fn session_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|w| w.session_storage().ok().flatten())
+}
@@ -0,0 +1,184 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use serde::Serialize;
+use crate::config::Config;
+
+const RING_CAPACITY: usize = 200;
+const FLUSH_BATCH_SIZE: usize = 20;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchTiming {
+    // `Date.now()` when the request started.
+    pub started_at: f64,
+    pub network_ms: f64,
+    pub decode_ms: f64,
+    pub render_ms: Option<f64>,
+    pub result_count: usize,
+}
+
+impl SearchTiming {
+    pub fn total_ms(&self) -> f64 {
+        self.network_ms + self.decode_ms + self.render_ms.unwrap_or(0.0)
+    }
+}
+
+pub struct RingBuffer<T> {
+    capacity: usize,
+    items: VecDeque<T>,
+}
+
+impl<T: Clone> RingBuffer<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.items.len() == self.capacity {
+            self.items.pop_front();
+        }
+        self.items.push_back(item);
+    }
+
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.items.back_mut()
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.items.iter().cloned().collect()
+    }
+}
+
+struct Telemetry {
+    timings: RingBuffer<SearchTiming>,
+    // Timings not yet posted to the metrics endpoint.
+    pending: Vec<SearchTiming>,
+}
+
+thread_local! {
+    static TELEMETRY: RefCell<Telemetry> = RefCell::new(Telemetry {
+        timings: RingBuffer::new(RING_CAPACITY),
+        pending: Vec::new(),
+    });
+}
+
+// High-resolution clock in milliseconds; falls back to wall time.
+pub fn perf_now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now())
+        .unwrap_or_else(js_sys::Date::now)
+}
+
+pub fn record_search_timing(timing: SearchTiming) {
+    TELEMETRY.with(|t| t.borrow_mut().timings.push(timing));
+}
+
+// Render time is only known once the browser has painted the results, well
+// after the request finished, so it is attached to the latest sample.
+pub fn record_render_time(render_ms: f64) {
+    TELEMETRY.with(|t| {
+        let mut t = t.borrow_mut();
+        let Some(last) = t.timings.last_mut() else { return };
+        last.render_ms = Some(render_ms);
+        let completed = last.clone();
+        t.pending.push(completed);
+    });
+
+    if TELEMETRY.with(|t| t.borrow().pending.len()) >= FLUSH_BATCH_SIZE {
+        wasm_bindgen_futures::spawn_local(async {
+            let _ = flush_telemetry().await;
+        });
+    }
+}
+
+pub fn recent_timings() -> Vec<SearchTiming> {
+    TELEMETRY.with(|t| t.borrow().timings.to_vec())
+}
+
+// Nearest-rank percentile; `p` is in 0..=100.
+pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+pub async fn flush_telemetry() -> Result<(), String> {
+    let Some(endpoint) = Config::get_telemetry_endpoint() else {
+        TELEMETRY.with(|t| t.borrow_mut().pending.clear());
+        return Ok(());
+    };
+
+    let batch = TELEMETRY.with(|t| std::mem::take(&mut t.borrow_mut().pending));
+    if batch.is_empty() {
+        return Ok(());
+    }
+
+    let result = post_batch(&endpoint, &batch).await;
+    if result.is_err() {
+        requeue(batch);
+    }
+    result
+}
+
+// A failed upload goes back in front of anything recorded since, so the next
+// flush retries it; the oldest samples are dropped past the ring's capacity.
+fn requeue(batch: Vec<SearchTiming>) {
+    TELEMETRY.with(|t| {
+        let pending = &mut t.borrow_mut().pending;
+        let newer = std::mem::replace(pending, batch);
+        pending.extend(newer);
+        let overflow = pending.len().saturating_sub(RING_CAPACITY);
+        pending.drain(..overflow);
+    });
+}
+
+async fn post_batch(endpoint: &str, batch: &[SearchTiming]) -> Result<(), String> {
+    let response = gloo_net::http::Request::post(endpoint)
+        .json(batch)
+        .map_err(|e| format!("Failed to serialize: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    if response.ok() {
+        Ok(())
+    } else {
+        Err(format!("Metrics upload failed with status {}", response.status()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(started_at: f64) -> SearchTiming {
+        SearchTiming { started_at, network_ms: 0.0, decode_ms: 0.0, render_ms: None, result_count: 0 }
+    }
+
+    #[test]
+    fn failed_batch_is_retried_before_newer_timings() {
+        TELEMETRY.with(|t| t.borrow_mut().pending = vec![timing(3.0)]);
+        requeue(vec![timing(1.0), timing(2.0)]);
+
+        let pending: Vec<f64> = TELEMETRY.with(|t| t.borrow().pending.iter().map(|t| t.started_at).collect());
+        assert_eq!(pending, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn requeue_drops_the_oldest_past_capacity() {
+        TELEMETRY.with(|t| t.borrow_mut().pending = vec![timing(RING_CAPACITY as f64)]);
+        requeue((0..RING_CAPACITY).map(|i| timing(i as f64)).collect());
+
+        let pending = TELEMETRY.with(|t| t.borrow().pending.clone());
+        assert_eq!(pending.len(), RING_CAPACITY);
+        assert_eq!(pending[0].started_at, 1.0);
+        assert_eq!(pending[RING_CAPACITY - 1].started_at, RING_CAPACITY as f64);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::models::*;
+
+// Resources run on the server during SSR, where the browser fetch API used by
+// the rest of this module doesn't exist, so they go through reqwest instead.
+#[cfg(feature = "ssr")]
+pub(crate) mod server {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use leptos::ServerFnError;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use crate::config::Config;
+    use crate::controllers::retry::{parse_retry_after_with, UpstreamError};
+
+    pub type ServerResult<T> = Result<T, ServerFnError<UpstreamError>>;
+
+    fn url(path: &str) -> String {
+        format!("{}{}", Config::get_full_backend_url(), path)
+    }
+
+    fn millis_since_epoch(time: SystemTime) -> Option<f64> {
+        Some(time.duration_since(UNIX_EPOCH).ok()?.as_millis() as f64)
+    }
+
+    fn retry_after_ms(response: &reqwest::Response) -> Option<u64> {
+        let value = response.headers().get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+        let now_ms = millis_since_epoch(SystemTime::now())?;
+        parse_retry_after_with(value, now_ms, |date| {
+            millis_since_epoch(httpdate::parse_http_date(date).ok()?)
+        })
+    }
+
+    async fn send(request: reqwest::RequestBuilder, bearer: Option<&str>) -> ServerResult<reqwest::Response> {
+        let request = match bearer {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ServerFnError::ServerError(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after_ms = retry_after_ms(&response);
+            let body = response.text().await.unwrap_or_default();
+            return Err(UpstreamError { status: status.as_u16(), retry_after_ms, body }.into());
+        }
+        Ok(response)
+    }
+
+    async fn decode<T: DeserializeOwned>(response: reqwest::Response) -> ServerResult<T> {
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ServerFnError::ServerError(format!("Failed to parse response: {}", e)))
+    }
+
+    pub async fn get_json<Q: Serialize + ?Sized, T: DeserializeOwned>(path: &str, query: &Q) -> ServerResult<T> {
+        let response = send(reqwest::Client::new().get(url(path)).query(query), None).await?;
+        decode(response).await
+    }
+
+    pub async fn post_json<B: Serialize, T: DeserializeOwned>(
+        path: &str,
+        body: &B,
+        bearer: Option<&str>,
+    ) -> ServerResult<T> {
+        let response = send(reqwest::Client::new().post(url(path)).json(body), bearer).await?;
+        decode(response).await
+    }
+
+    pub async fn post<B: Serialize>(path: &str, body: &B, bearer: Option<&str>) -> ServerResult<()> {
+        send(reqwest::Client::new().post(url(path)).json(body), bearer).await?;
+        Ok(())
+    }
+
+    // Unlike the helpers above, a non-2xx status is returned rather than
+    // treated as an error, for callers that need to tell the two apart.
+    pub async fn get_with_status(path: &str) -> Result<(u16, String), String> {
+        let response = reqwest::get(url(path))
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+        let status = response.status().as_u16();
+        let text = response.text().await.unwrap_or_default();
+        Ok((status, text))
+    }
+}
+
+pub async fn fetch_search(query: String, k: usize, index: String) -> Result<SearchResponse, String> {
+    #[cfg(feature = "ssr")]
+    {
+        server::post_json("/reviews/search", &SearchQuery { query, k, index }, None)
+            .await
+            .map_err(|e| super::retry::ApiError::from(e).to_string())
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        super::api::search_reviews(query, k, index).await.map_err(|e| e.to_string())
+    }
+}
+
+pub async fn fetch_product_reviews(product_id: String, index: String) -> Result<Vec<Review>, String> {
+    #[cfg(feature = "ssr")]
+    {
+        server::get_json("/reviews", &[("product_id", product_id), ("index", index)])
+            .await
+            .map_err(|e| super::retry::ApiError::from(e).to_string())
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        super::api::get_product_reviews(product_id, index).await.map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,75 @@
+use axum::body::{to_bytes, Body};
+use axum::extract::Request;
+use axum::http::{header, HeaderName, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{any, post};
+use axum::Router;
+use leptos::*;
+use leptos_axum::{generate_route_list, handle_server_fns, LeptosRoutes};
+use tower_http::services::ServeDir;
+use frontend::config::Config;
+use frontend::views::App;
+
+const MAX_PROXY_BODY: usize = 10 * 1024 * 1024;
+const FORWARDED_REQUEST_HEADERS: [HeaderName; 3] = [header::AUTHORIZATION, header::CONTENT_TYPE, header::ACCEPT];
+const FORWARDED_RESPONSE_HEADERS: [HeaderName; 2] = [header::CONTENT_TYPE, header::RETRY_AFTER];
+
+// Hydrated pages call `/api/...` from the browser just like the CSR build,
+// so forward them to the backend with the prefix stripped, as nginx does.
+async fn proxy_api(client: reqwest::Client, request: Request) -> Response {
+    let (parts, body) = request.into_parts();
+    let path = parts.uri.path().strip_prefix("/api").unwrap_or(parts.uri.path());
+    let query = parts.uri.query().map(|q| format!("?{}", q)).unwrap_or_default();
+    let url = format!("{}{}{}", Config::get_full_backend_url(), path, query);
+
+    let Ok(body) = to_bytes(body, MAX_PROXY_BODY).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+    let mut upstream = client.request(parts.method, url).body(body);
+    for name in FORWARDED_REQUEST_HEADERS {
+        if let Some(value) = parts.headers.get(&name) {
+            upstream = upstream.header(name, value);
+        }
+    }
+
+    let response = match upstream.send().await {
+        Ok(response) => response,
+        Err(e) => return (StatusCode::BAD_GATEWAY, format!("Backend unreachable: {}", e)).into_response(),
+    };
+    let mut builder = Response::builder().status(response.status());
+    for name in FORWARDED_RESPONSE_HEADERS {
+        if let Some(value) = response.headers().get(&name) {
+            builder = builder.header(name, value);
+        }
+    }
+    match response.bytes().await {
+        Ok(bytes) => builder.body(Body::from(bytes)).unwrap_or_else(|_| StatusCode::BAD_GATEWAY.into_response()),
+        Err(e) => (StatusCode::BAD_GATEWAY, format!("Backend response failed: {}", e)).into_response(),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let conf = get_configuration(None).await.expect("failed to read leptos configuration");
+    let leptos_options = conf.leptos_options;
+    let addr = leptos_options.site_addr;
+    let routes = generate_route_list(App);
+
+    // Server functions live under /rpc so they don't collide with the /api
+    // prefix, which this server proxies to the backend like nginx and Trunk.
+    let client = reqwest::Client::new();
+    let app = Router::new()
+        .route("/rpc/*fn_name", post(handle_server_fns))
+        .route("/api/*path", any(move |request: Request| proxy_api(client.clone(), request)))
+        .leptos_routes(&leptos_options, routes, App)
+        .fallback_service(ServeDir::new(leptos_options.site_root.clone()))
+        .with_state(leptos_options);
+
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .expect("failed to bind server address");
+    println!("listening on http://{}", addr);
+    axum::serve(listener, app.into_make_service())
+        .await
+        .expect("server error");
+}
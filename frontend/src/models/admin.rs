@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexStats {
+    pub index: String,
+    #[serde(default)]
+    pub document_count: u64,
+    // Entries marked deleted but still occupying space until the next compaction.
+    #[serde(default)]
+    pub deleted_count: u64,
+    #[serde(default)]
+    pub size_bytes: u64,
+    #[serde(default)]
+    pub dimensions: Option<u32>,
+    // Timestamps are Unix seconds.
+    #[serde(default)]
+    pub last_compacted_at: Option<i64>,
+    #[serde(default)]
+    pub last_snapshot_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub id: String,
+    // Unix seconds.
+    pub created_at: i64,
+    #[serde(default)]
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    Reindex,
+    Compact,
+    Snapshot,
+    Restore,
+}
+
+impl JobKind {
+    // Message catalog key for the job name.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            JobKind::Reindex => "job.reindex",
+            JobKind::Compact => "job.compact",
+            JobKind::Snapshot => "job.snapshot",
+            JobKind::Restore => "job.restore",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, JobStatus::Succeeded | JobStatus::Failed)
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            JobStatus::Queued => "job-queued",
+            JobStatus::Running => "job-running",
+            JobStatus::Succeeded => "job-succeeded",
+            JobStatus::Failed => "job-failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    pub id: String,
+    pub kind: JobKind,
+    pub status: JobStatus,
+    // Fraction complete in `0.0..=1.0`, when the backend can estimate it.
+    #[serde(default)]
+    pub progress: Option<f64>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl Job {
+    pub fn percent(&self) -> Option<u8> {
+        self.progress.map(|p| (p.clamp(0.0, 1.0) * 100.0).round() as u8)
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    // Lifetime of the access token in seconds.
+    pub expires_in: u64,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthSession {
+    pub username: String,
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    // Milliseconds since the Unix epoch, as returned by `Date.now()`.
+    pub expires_at: f64,
+}
+
+impl AuthSession {
+    pub fn from_token(username: String, token: TokenResponse, now_ms: f64) -> Self {
+        Self {
+            username,
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            expires_at: now_ms + token.expires_in as f64 * 1000.0,
+        }
+    }
+
+    pub fn is_expired(&self, now_ms: f64) -> bool {
+        now_ms >= self.expires_at
+    }
+}
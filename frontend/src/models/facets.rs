@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use super::search::SearchResult;
+
+const TOP_PRODUCTS: usize = 5;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResultFacets {
+    // Count per star rating; index 0 holds 1-star reviews.
+    pub ratings: [usize; 5],
+    pub top_products: Vec<(String, usize)>,
+}
+
+impl ResultFacets {
+    pub fn from_results(results: &[SearchResult]) -> Self {
+        let mut ratings = [0usize; 5];
+        let mut products: HashMap<&str, usize> = HashMap::new();
+
+        for result in results {
+            if (1..=5).contains(&result.review_rating) {
+                ratings[(result.review_rating - 1) as usize] += 1;
+            }
+            *products.entry(result.product_id.as_str()).or_default() += 1;
+        }
+
+        let mut top_products: Vec<(String, usize)> = products
+            .into_iter()
+            .map(|(id, count)| (id.to_string(), count))
+            .collect();
+        top_products.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_products.truncate(TOP_PRODUCTS);
+
+        Self { ratings, top_products }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FacetFilter {
+    pub rating: Option<i32>,
+    pub product_id: Option<String>,
+}
+
+impl FacetFilter {
+    pub fn is_empty(&self) -> bool {
+        self.rating.is_none() && self.product_id.is_none()
+    }
+
+    pub fn matches(&self, result: &SearchResult) -> bool {
+        self.rating.is_none_or(|r| result.review_rating == r)
+            && self
+                .product_id
+                .as_deref()
+                .is_none_or(|id| result.product_id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(product_id: &str, rating: i32) -> SearchResult {
+        SearchResult {
+            review_title: String::new(),
+            review_body: String::new(),
+            product_id: product_id.to_string(),
+            review_rating: rating,
+            score: 0.0,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn ratings_are_counted_per_star_and_out_of_range_ones_skipped() {
+        let results = [result("A", 5), result("A", 1), result("B", 5), result("B", 0), result("C", 9)];
+        let facets = ResultFacets::from_results(&results);
+
+        assert_eq!(facets.ratings, [1, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn top_products_sort_by_count_then_id_and_keep_five() {
+        let ids = ["F", "E", "E", "D", "C", "B", "A", "A", "A"];
+        let results: Vec<_> = ids.iter().map(|id| result(id, 3)).collect();
+        let facets = ResultFacets::from_results(&results);
+
+        let top: Vec<_> = facets.top_products.iter().map(|(id, n)| (id.as_str(), *n)).collect();
+        assert_eq!(top, vec![("A", 3), ("E", 2), ("B", 1), ("C", 1), ("D", 1)]);
+    }
+
+    #[test]
+    fn filter_requires_every_set_field_to_match() {
+        let review = result("A", 4);
+
+        assert!(FacetFilter::default().is_empty());
+        assert!(FacetFilter::default().matches(&review));
+        assert!(FacetFilter { rating: Some(4), product_id: None }.matches(&review));
+        assert!(!FacetFilter { rating: Some(4), product_id: Some("B".into()) }.matches(&review));
+        assert!(!FacetFilter { rating: Some(5), product_id: Some("A".into()) }.matches(&review));
+    }
+}
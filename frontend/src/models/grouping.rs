@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use super::search::SearchResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductGroup {
+    pub product_id: String,
+    // Indices into the grouped results, best (lowest distance) first.
+    pub members: Vec<usize>,
+    pub average_rating: f64,
+}
+
+// Groups keep the order in which their product first appears, so a grouped
+// view follows whatever ordering (original or re-ranked) the caller used.
+pub fn group_by_product<'a>(results: impl IntoIterator<Item = &'a SearchResult>) -> Vec<ProductGroup> {
+    let results: Vec<&SearchResult> = results.into_iter().collect();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    let mut groups: Vec<ProductGroup> = Vec::new();
+
+    for (idx, result) in results.iter().enumerate() {
+        let slot = *index.entry(result.product_id.as_str()).or_insert_with(|| {
+            groups.push(ProductGroup {
+                product_id: result.product_id.clone(),
+                members: Vec::new(),
+                average_rating: 0.0,
+            });
+            groups.len() - 1
+        });
+        groups[slot].members.push(idx);
+    }
+
+    for group in &mut groups {
+        group
+            .members
+            .sort_by(|&a, &b| results[a].score.total_cmp(&results[b].score));
+        let sum: i32 = group.members.iter().map(|&i| results[i].review_rating).sum();
+        group.average_rating = sum as f64 / group.members.len() as f64;
+    }
+
+    groups
+}
+
+// Moves every item into its group, in `members` order. `items` must be the
+// sequence the groups were built from; each item lands in exactly one group.
+pub fn split_by_group<T>(groups: &[ProductGroup], items: Vec<T>) -> Vec<Vec<T>> {
+    let mut slots: Vec<Option<T>> = items.into_iter().map(Some).collect();
+    groups
+        .iter()
+        .map(|group| group.members.iter().filter_map(|&i| slots.get_mut(i)?.take()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(product_id: &str, score: f32, rating: i32) -> SearchResult {
+        SearchResult {
+            review_title: String::new(),
+            review_body: String::new(),
+            product_id: product_id.to_string(),
+            review_rating: rating,
+            score,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn groups_follow_first_appearance_with_best_member_first() {
+        let results = [result("B", 0.4, 2), result("A", 0.3, 5), result("B", 0.1, 4)];
+        let groups = group_by_product(&results);
+
+        assert_eq!(
+            groups,
+            vec![
+                ProductGroup { product_id: "B".into(), members: vec![2, 0], average_rating: 3.0 },
+                ProductGroup { product_id: "A".into(), members: vec![1], average_rating: 5.0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn split_moves_each_item_into_its_group() {
+        let results = [result("B", 0.4, 2), result("A", 0.3, 5), result("B", 0.1, 4)];
+        let groups = group_by_product(&results);
+
+        let split = split_by_group(&groups, vec!["b-far", "a", "b-close"]);
+        assert_eq!(split, vec![vec!["b-close", "b-far"], vec!["a"]]);
+    }
+
+    #[test]
+    fn split_skips_indices_past_the_items() {
+        let groups = [ProductGroup { product_id: "A".into(), members: vec![0, 3], average_rating: 1.0 }];
+
+        assert_eq!(split_by_group(&groups, vec![10]), vec![vec![10]]);
+        assert_eq!(split_by_group::<i32>(&[], vec![1, 2]), Vec::<Vec<i32>>::new());
+    }
+}
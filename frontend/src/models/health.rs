@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HealthResponse {
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub indexed_documents: Option<u64>,
+}
+
+impl HealthResponse {
+    pub fn is_healthy(&self) -> bool {
+        matches!(self.status.to_ascii_lowercase().as_str(), "ok" | "healthy" | "up")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackendStatus {
+    Checking,
+    Connected,
+    Degraded,
+    Down,
+}
+
+impl BackendStatus {
+    // Message catalog key for the status name.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            BackendStatus::Checking => "status.checking",
+            BackendStatus::Connected => "status.connected",
+            BackendStatus::Degraded => "status.degraded",
+            BackendStatus::Down => "status.down",
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            BackendStatus::Checking => "status-checking",
+            BackendStatus::Connected => "status-connected",
+            BackendStatus::Degraded => "status-degraded",
+            BackendStatus::Down => "status-down",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthState {
+    pub status: BackendStatus,
+    pub version: Option<String>,
+    pub indexed_documents: Option<u64>,
+    pub last_checked: Option<String>,
+    pub detail: Option<String>,
+}
+
+impl Default for HealthState {
+    fn default() -> Self {
+        Self {
+            status: BackendStatus::Checking,
+            version: None,
+            indexed_documents: None,
+            last_checked: None,
+            detail: None,
+        }
+    }
+}
+
+impl HealthState {
+    pub fn is_unreachable(&self) -> bool {
+        self.status == BackendStatus::Down
+    }
+}
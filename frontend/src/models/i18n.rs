@@ -0,0 +1,442 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    Th,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Th];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Th => "th",
+        }
+    }
+
+    // Shown in the switcher in its own language, so it's recognisable
+    // whichever locale is active.
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Th => "ไทย",
+        }
+    }
+
+    // Accepts plain codes as well as browser tags such as `th-TH` or `en-GB`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        Locale::ALL.into_iter().find(|l| l.code() == primary)
+    }
+
+    // English distinguishes one from many; Thai never inflects for number.
+    pub fn plural_category(&self, count: u64) -> PluralCategory {
+        match self {
+            Locale::En if count == 1 => PluralCategory::One,
+            Locale::En | Locale::Th => PluralCategory::Other,
+        }
+    }
+
+    // Both shipped locales group thousands with ',' and use '.' for decimals
+    // (everyday Thai text uses Arabic numerals); a new locale only needs its pair.
+    fn separators(&self) -> (char, char) {
+        match self {
+            Locale::En | Locale::Th => (',', '.'),
+        }
+    }
+
+    fn catalog(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::En => EN,
+            Locale::Th => TH,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    One,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            PluralCategory::One => "one",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+// Missing translations fall back to English, then to the key itself so a
+// typo shows up on screen instead of as a blank.
+pub fn translate(locale: Locale, key: &str) -> &str {
+    let lookup = |locale: Locale| locale.catalog().iter().find(|(k, _)| *k == key).map(|(_, v)| *v);
+    lookup(locale).or_else(|| lookup(Locale::En)).unwrap_or(key)
+}
+
+// Replaces `{name}` placeholders with the matching argument.
+pub fn interpolate(template: &str, args: &[(&str, String)]) -> String {
+    args.iter().fold(template.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), value)
+    })
+}
+
+pub fn format_number(locale: Locale, value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let (group, decimal) = locale.separators();
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (int_part, frac_part) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let mut grouped = String::new();
+    for (i, digit) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(group);
+        }
+        grouped.push(digit);
+    }
+    if !frac_part.is_empty() {
+        grouped.push(decimal);
+        grouped.push_str(frac_part);
+    }
+    if value < 0.0 && grouped.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        grouped.insert(0, '-');
+    }
+    grouped
+}
+
+const EN: &[(&str, &str)] = &[
+    ("app.title", "SPFresh Review System"),
+    ("app.admin", "Admin"),
+    ("app.proxied", "proxied by the frontend server"),
+    ("locale.label", "Language"),
+    ("theme.label", "Theme"),
+    ("theme.system", "System theme"),
+    ("theme.light", "Light"),
+    ("theme.dark", "Dark"),
+    ("theme.high_contrast", "High contrast"),
+    ("index.label", "Index:"),
+    ("error.generic", "✗ Error: {message}"),
+    ("toast.region", "Notifications"),
+    ("toast.dismiss", "Dismiss notification"),
+    ("toast.retry", "Retry"),
+    ("toast.undo", "Undo"),
+    ("review.heading", "Add Review"),
+    ("review.title_label", "Review Title:"),
+    ("review.body_label", "Review Body:"),
+    ("review.product_label", "Product ID:"),
+    ("autocomplete.products", "Existing products"),
+    ("autocomplete.new_product", "No reviews for “{id}” yet. Submitting will add it as a new product."),
+    ("review.rating_label", "Rating (1-5):"),
+    ("review.rating_hint", "Click a star, or use the arrow keys. 1 is worst, 5 is best."),
+    ("review.submit", "Submit Review"),
+    ("review.submitting", "Submitting..."),
+    ("review.submitted", "✓ Review submitted successfully!"),
+    ("review.rate_limited", "Too many requests. You can submit again in"),
+    ("search.heading", "Search Reviews"),
+    ("search.query_label", "Search Query:"),
+    ("search.query_placeholder", "e.g., SPFresh"),
+    ("search.k_label", "Number of Results (k):"),
+    ("search.k_hint", "A whole number, 1 or more."),
+    ("search.submit", "Search"),
+    ("search.searching", "Searching..."),
+    ("search.still_searching", "Still searching…"),
+    ("search.cancel", "Cancel"),
+    ("search.empty_query", "Please enter a search query"),
+    ("search.found.one", "✓ Found {count} result"),
+    ("search.found.other", "✓ Found {count} results"),
+    ("search.no_results", "No results found"),
+    ("search.results_heading", "Search Results"),
+    ("search.results_list", "Search results. Use the up and down arrow keys to move between reviews."),
+    ("search.cached", "cached"),
+    ("search.cached_title", "Served from the local search cache"),
+    ("search.refresh", "↻ Refresh"),
+    ("search.save", "☆ Save search"),
+    ("search.saved", "★ Saved"),
+    ("search.unsaved", "Removed \"{query}\" from saved searches"),
+    ("suggest.label", "Suggested searches"),
+    ("suggest.saved", "saved"),
+    ("suggest.history", "recent"),
+    ("suggest.popular", "popular"),
+    ("search.export_csv", "Export CSV"),
+    ("search.export_json", "Export JSON"),
+    ("search.export_failed", "✗ Export failed: {message}"),
+    ("search.group_by_product", "Group by product"),
+    ("search.hidden.one", "{count} weaker match hidden by the relevance cutoff"),
+    ("search.hidden.other", "{count} weaker matches hidden by the relevance cutoff"),
+    ("search.busy_retry", "Backend is busy, retrying in"),
+    ("search.rate_limited", "Too many searches. You can search again in"),
+    ("countdown.seconds", "{seconds}s"),
+    ("result.review", "Review:"),
+    ("result.rating", "Rating:"),
+    ("result.product", "Product ID:"),
+    ("result.originally", "(originally #{rank})"),
+    ("result.untitled", "Untitled"),
+    ("result.no_content", "No content"),
+    ("result.not_available", "N/A"),
+    ("rating.summary", "{rating} out of 5 stars"),
+    ("rating.stars.one", "{count} star"),
+    ("rating.stars.other", "{count} stars"),
+    ("relevance.label", "{relevance}% relevant · distance {distance}"),
+    ("relevance.cutoff", "Minimum relevance: {relevance}%"),
+    (
+        "score.explanation",
+        "Score is the SPFresh vector distance between your query and the review (lower is closer). \
+         Relevance puts it on a fixed scale: a distance of 0 is 100%, 1 is 50% and 3 is 25%.",
+    ),
+    ("product.heading", "Product {id}"),
+    ("product.summary.one", "{count} review · avg rating {rating}/5"),
+    ("product.summary.other", "{count} reviews · avg rating {rating}/5"),
+    ("product.back", "← Back to search"),
+    ("product.loading", "Loading reviews..."),
+    ("product.empty", "No reviews for this product yet."),
+    ("group.hide_others", "Hide other reviews"),
+    ("group.show_more.one", "Show {count} more from this product"),
+    ("group.show_more.other", "Show {count} more from this product"),
+    ("facets.rating", "Rating:"),
+    ("facets.top_products", "Top products:"),
+    ("facets.product_chip", "Product {id} ({count})"),
+    ("facets.clear", "Clear filters"),
+    ("rerank.toggle", "Re-rank results"),
+    ("rerank.relevance", "Relevance"),
+    ("rerank.rating", "Rating"),
+    ("rerank.length", "Review length"),
+    ("rerank.recency", "Recency"),
+    ("rerank.reset", "Reset weights"),
+    ("status.checking", "Checking"),
+    ("status.connected", "Connected"),
+    ("status.degraded", "Degraded"),
+    ("status.down", "Down"),
+    ("status.backend", "Backend:"),
+    ("status.version", "Version:"),
+    ("status.indexed", "Indexed reviews:"),
+    ("status.last_checked", "Last checked {time}"),
+    ("status.unreachable_notice", "The backend is unreachable, so this action is disabled until it comes back."),
+    ("auth.signed_in_as", "Signed in as"),
+    ("auth.sign_out", "Sign out"),
+    ("auth.signed_out", "Signed out."),
+    ("auth.username", "Username"),
+    ("auth.password", "Password"),
+    ("auth.sign_in", "Sign in"),
+    ("auth.signing_in", "Signing in..."),
+    ("auth.required", "Sign in to add or manage reviews."),
+    ("telemetry.heading", "Search latency (debug)"),
+    ("telemetry.empty", "No searches recorded yet."),
+    ("telemetry.samples", "Samples:"),
+    ("telemetry.avg_network", "avg network {value}"),
+    ("telemetry.avg_decode", "avg decode {value}"),
+    ("telemetry.avg_render", "avg render {value}"),
+    ("telemetry.ms", "{value} ms"),
+    ("admin.heading", "Index {index}"),
+    ("admin.loading_stats", "Loading index stats..."),
+    ("admin.documents", "Documents"),
+    ("admin.deleted", "Deleted (pending compaction)"),
+    ("admin.size", "Size on disk"),
+    ("admin.dimensions", "Dimensions"),
+    ("admin.last_compacted", "Last compacted"),
+    ("admin.last_snapshot", "Last snapshot"),
+    ("admin.never", "never"),
+    ("admin.reindex", "Reindex"),
+    ("admin.compact", "Compact"),
+    ("admin.create_snapshot", "Create snapshot"),
+    ("admin.snapshots", "Snapshots"),
+    ("admin.loading_snapshots", "Loading snapshots..."),
+    ("admin.no_snapshots", "No snapshots yet."),
+    ("admin.restore", "Restore"),
+    ("admin.confirm_restore", "Restore snapshot {id}? The current index contents will be replaced."),
+    ("job.reindex", "Reindex"),
+    ("job.compact", "Compaction"),
+    ("job.snapshot", "Snapshot"),
+    ("job.restore", "Restore"),
+    ("job.queued", "queued"),
+    ("job.running", "running"),
+    ("job.done", "done"),
+    ("job.failed", "failed"),
+    ("job.finished_notice", "{job} finished"),
+    ("job.failed_notice", "{job} failed"),
+    ("job.lost_notice", "Stopped tracking {job}: {message}"),
+];
+
+const TH: &[(&str, &str)] = &[
+    ("app.title", "ระบบรีวิว SPFresh"),
+    ("app.admin", "ผู้ดูแลระบบ"),
+    ("app.proxied", "ผ่านพร็อกซีของเซิร์ฟเวอร์ฟรอนต์เอนด์"),
+    ("locale.label", "ภาษา"),
+    ("theme.label", "ธีม"),
+    ("theme.system", "ตามระบบ"),
+    ("theme.light", "สว่าง"),
+    ("theme.dark", "มืด"),
+    ("theme.high_contrast", "คอนทราสต์สูง"),
+    ("index.label", "ดัชนี:"),
+    ("error.generic", "✗ เกิดข้อผิดพลาด: {message}"),
+    ("toast.region", "การแจ้งเตือน"),
+    ("toast.dismiss", "ปิดการแจ้งเตือน"),
+    ("toast.retry", "ลองอีกครั้ง"),
+    ("toast.undo", "เลิกทำ"),
+    ("review.heading", "เพิ่มรีวิว"),
+    ("review.title_label", "หัวข้อรีวิว:"),
+    ("review.body_label", "เนื้อหารีวิว:"),
+    ("review.product_label", "รหัสสินค้า:"),
+    ("autocomplete.products", "สินค้าที่มีอยู่"),
+    ("autocomplete.new_product", "ยังไม่มีรีวิวสำหรับ “{id}” การส่งรีวิวนี้จะเพิ่มเป็นสินค้าใหม่"),
+    ("review.rating_label", "คะแนน (1-5):"),
+    ("review.rating_hint", "คลิกที่ดาวหรือใช้ปุ่มลูกศร 1 คือแย่ที่สุด 5 คือดีที่สุด"),
+    ("review.submit", "ส่งรีวิว"),
+    ("review.submitting", "กำลังส่ง..."),
+    ("review.submitted", "✓ ส่งรีวิวเรียบร้อยแล้ว!"),
+    ("review.rate_limited", "มีคำขอมากเกินไป ส่งใหม่ได้ในอีก"),
+    ("search.heading", "ค้นหารีวิว"),
+    ("search.query_label", "คำค้นหา:"),
+    ("search.query_placeholder", "เช่น SPFresh"),
+    ("search.k_label", "จำนวนผลลัพธ์ (k):"),
+    ("search.k_hint", "จำนวนเต็มตั้งแต่ 1 ขึ้นไป"),
+    ("search.submit", "ค้นหา"),
+    ("search.searching", "กำลังค้นหา..."),
+    ("search.still_searching", "ยังค้นหาอยู่…"),
+    ("search.cancel", "ยกเลิก"),
+    ("search.empty_query", "กรุณากรอกคำค้นหา"),
+    ("search.found.other", "✓ พบ {count} รายการ"),
+    ("search.no_results", "ไม่พบผลลัพธ์"),
+    ("search.results_heading", "ผลการค้นหา"),
+    ("search.results_list", "ผลการค้นหา ใช้ปุ่มลูกศรขึ้นและลงเพื่อเลื่อนระหว่างรีวิว"),
+    ("search.cached", "แคช"),
+    ("search.cached_title", "แสดงจากแคชการค้นหาในเครื่อง"),
+    ("search.refresh", "↻ รีเฟรช"),
+    ("search.save", "☆ บันทึกการค้นหา"),
+    ("search.saved", "★ บันทึกแล้ว"),
+    ("search.unsaved", "นำ \"{query}\" ออกจากการค้นหาที่บันทึกไว้แล้ว"),
+    ("suggest.label", "คำค้นหาที่แนะนำ"),
+    ("suggest.saved", "บันทึกไว้"),
+    ("suggest.history", "ล่าสุด"),
+    ("suggest.popular", "ยอดนิยม"),
+    ("search.export_csv", "ส่งออก CSV"),
+    ("search.export_json", "ส่งออก JSON"),
+    ("search.export_failed", "✗ ส่งออกไม่สำเร็จ: {message}"),
+    ("search.group_by_product", "จัดกลุ่มตามสินค้า"),
+    ("search.hidden.other", "ซ่อน {count} รายการที่มีความเกี่ยวข้องต่ำกว่าเกณฑ์"),
+    ("search.busy_retry", "แบ็กเอนด์ไม่ว่าง จะลองใหม่ในอีก"),
+    ("search.rate_limited", "ค้นหาบ่อยเกินไป ค้นหาใหม่ได้ในอีก"),
+    ("countdown.seconds", "{seconds} วินาที"),
+    ("result.review", "รีวิว:"),
+    ("result.rating", "คะแนน:"),
+    ("result.product", "รหัสสินค้า:"),
+    ("result.originally", "(เดิมอันดับ {rank})"),
+    ("result.untitled", "ไม่มีหัวข้อ"),
+    ("result.no_content", "ไม่มีเนื้อหา"),
+    ("result.not_available", "ไม่ระบุ"),
+    ("rating.summary", "{rating} จาก 5 ดาว"),
+    ("rating.stars.other", "{count} ดาว"),
+    ("relevance.label", "เกี่ยวข้อง {relevance}% · ระยะห่าง {distance}"),
+    ("relevance.cutoff", "ความเกี่ยวข้องขั้นต่ำ: {relevance}%"),
+    (
+        "score.explanation",
+        "คะแนนคือระยะห่างเวกเตอร์ของ SPFresh ระหว่างคำค้นหากับรีวิว (ยิ่งน้อยยิ่งใกล้) \
+         ความเกี่ยวข้องใช้สเกลคงที่: ระยะห่าง 0 คือ 100% ระยะห่าง 1 คือ 50% และระยะห่าง 3 คือ 25%",
+    ),
+    ("product.heading", "สินค้า {id}"),
+    ("product.summary.other", "{count} รีวิว · คะแนนเฉลี่ย {rating}/5"),
+    ("product.back", "← กลับไปที่การค้นหา"),
+    ("product.loading", "กำลังโหลดรีวิว..."),
+    ("product.empty", "ยังไม่มีรีวิวสำหรับสินค้านี้"),
+    ("group.hide_others", "ซ่อนรีวิวอื่น"),
+    ("group.show_more.other", "แสดงอีก {count} รีวิวจากสินค้านี้"),
+    ("facets.rating", "คะแนน:"),
+    ("facets.top_products", "สินค้ายอดนิยม:"),
+    ("facets.product_chip", "สินค้า {id} ({count})"),
+    ("facets.clear", "ล้างตัวกรอง"),
+    ("rerank.toggle", "จัดอันดับผลลัพธ์ใหม่"),
+    ("rerank.relevance", "ความเกี่ยวข้อง"),
+    ("rerank.rating", "คะแนน"),
+    ("rerank.length", "ความยาวรีวิว"),
+    ("rerank.recency", "ความใหม่"),
+    ("rerank.reset", "รีเซ็ตน้ำหนัก"),
+    ("status.checking", "กำลังตรวจสอบ"),
+    ("status.connected", "เชื่อมต่อแล้ว"),
+    ("status.degraded", "ทำงานได้ไม่เต็มที่"),
+    ("status.down", "ไม่พร้อมใช้งาน"),
+    ("status.backend", "แบ็กเอนด์:"),
+    ("status.version", "เวอร์ชัน:"),
+    ("status.indexed", "รีวิวในดัชนี:"),
+    ("status.last_checked", "ตรวจสอบล่าสุด {time}"),
+    ("status.unreachable_notice", "ไม่สามารถติดต่อแบ็กเอนด์ได้ จึงปิดการทำงานนี้ไว้จนกว่าจะกลับมาใช้งานได้"),
+    ("auth.signed_in_as", "เข้าสู่ระบบในชื่อ"),
+    ("auth.sign_out", "ออกจากระบบ"),
+    ("auth.signed_out", "ออกจากระบบแล้ว"),
+    ("auth.username", "ชื่อผู้ใช้"),
+    ("auth.password", "รหัสผ่าน"),
+    ("auth.sign_in", "เข้าสู่ระบบ"),
+    ("auth.signing_in", "กำลังเข้าสู่ระบบ..."),
+    ("auth.required", "เข้าสู่ระบบเพื่อเพิ่มหรือจัดการรีวิว"),
+    ("telemetry.heading", "เวลาตอบสนองการค้นหา (ดีบัก)"),
+    ("telemetry.empty", "ยังไม่มีการค้นหาที่บันทึกไว้"),
+    ("telemetry.samples", "จำนวนตัวอย่าง:"),
+    ("telemetry.avg_network", "เครือข่ายเฉลี่ย {value}"),
+    ("telemetry.avg_decode", "ถอดรหัสเฉลี่ย {value}"),
+    ("telemetry.avg_render", "แสดงผลเฉลี่ย {value}"),
+    ("telemetry.ms", "{value} มิลลิวินาที"),
+    ("admin.heading", "ดัชนี {index}"),
+    ("admin.loading_stats", "กำลังโหลดสถิติดัชนี..."),
+    ("admin.documents", "เอกสาร"),
+    ("admin.deleted", "ถูกลบ (รอการบีบอัด)"),
+    ("admin.size", "ขนาดบนดิสก์"),
+    ("admin.dimensions", "จำนวนมิติ"),
+    ("admin.last_compacted", "บีบอัดล่าสุด"),
+    ("admin.last_snapshot", "สแนปช็อตล่าสุด"),
+    ("admin.never", "ไม่เคย"),
+    ("admin.reindex", "สร้างดัชนีใหม่"),
+    ("admin.compact", "บีบอัด"),
+    ("admin.create_snapshot", "สร้างสแนปช็อต"),
+    ("admin.snapshots", "สแนปช็อต"),
+    ("admin.loading_snapshots", "กำลังโหลดสแนปช็อต..."),
+    ("admin.no_snapshots", "ยังไม่มีสแนปช็อต"),
+    ("admin.restore", "กู้คืน"),
+    ("admin.confirm_restore", "กู้คืนสแนปช็อต {id} หรือไม่? ข้อมูลปัจจุบันในดัชนีจะถูกแทนที่"),
+    ("job.reindex", "สร้างดัชนีใหม่"),
+    ("job.compact", "การบีบอัด"),
+    ("job.snapshot", "สแนปช็อต"),
+    ("job.restore", "การกู้คืน"),
+    ("job.queued", "อยู่ในคิว"),
+    ("job.running", "กำลังทำงาน"),
+    ("job.done", "เสร็จสิ้น"),
+    ("job.failed", "ล้มเหลว"),
+    ("job.finished_notice", "{job} เสร็จสิ้น"),
+    ("job.failed_notice", "{job} ล้มเหลว"),
+    ("job.lost_notice", "หยุดติดตาม{job}: {message}"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_group_thousands() {
+        assert_eq!(format_number(Locale::En, 999.0, 0), "999");
+        assert_eq!(format_number(Locale::En, 1234.0, 0), "1,234");
+        assert_eq!(format_number(Locale::Th, 1234567.0, 0), "1,234,567");
+        assert_eq!(format_number(Locale::En, -1234.5, 1), "-1,234.5");
+    }
+
+    #[test]
+    fn rounding_happens_before_grouping() {
+        assert_eq!(format_number(Locale::En, 1234.567, 2), "1,234.57");
+        assert_eq!(format_number(Locale::En, 999.6, 0), "1,000");
+        assert_eq!(format_number(Locale::En, 4.0, 1), "4.0");
+    }
+
+    #[test]
+    fn values_rounding_to_zero_drop_the_sign() {
+        assert_eq!(format_number(Locale::En, -0.0, 0), "0");
+        assert_eq!(format_number(Locale::En, -0.004, 2), "0.00");
+        assert_eq!(format_number(Locale::En, -0.006, 2), "-0.01");
+    }
+
+    #[test]
+    fn plural_categories() {
+        assert_eq!(Locale::En.plural_category(1), PluralCategory::One);
+        assert_eq!(Locale::En.plural_category(0), PluralCategory::Other);
+        assert_eq!(Locale::En.plural_category(2), PluralCategory::Other);
+        assert_eq!(Locale::Th.plural_category(1), PluralCategory::Other);
+    }
+}
@@ -0,0 +1,5 @@
+pub const DEFAULT_INDEX: &str = "default";
+
+pub fn default_index() -> String {
+    DEFAULT_INDEX.to_string()
+}
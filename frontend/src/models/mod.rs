@@ -1,5 +1,33 @@
+pub mod admin;
+pub mod auth;
+pub mod facets;
+pub mod grouping;
+pub mod health;
+pub mod i18n;
+pub mod index;
+pub mod notification;
+pub mod product;
+pub mod rating;
 pub mod review;
+pub mod rerank;
+pub mod score;
 pub mod search;
+pub mod suggestion;
+pub mod theme;
 
+pub use admin::*;
+pub use auth::*;
+pub use facets::*;
+pub use grouping::*;
+pub use health::*;
+pub use i18n::*;
+pub use index::*;
+pub use notification::*;
+pub use product::*;
+pub use rating::*;
 pub use review::*;
-pub use search::*;
\ No newline at end of file
+pub use rerank::*;
+pub use score::*;
+pub use search::*;
+pub use suggestion::*;
+pub use theme::*;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Success,
+    Info,
+    Warning,
+    Error,
+}
+
+impl NotificationLevel {
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            NotificationLevel::Success => "toast-success",
+            NotificationLevel::Info => "toast-info",
+            NotificationLevel::Warning => "toast-warning",
+            NotificationLevel::Error => "toast-error",
+        }
+    }
+
+    // Warnings and errors interrupt a screen reader; the rest wait their turn.
+    pub fn is_urgent(&self) -> bool {
+        matches!(self, NotificationLevel::Warning | NotificationLevel::Error)
+    }
+
+    // Errors stay until dismissed so they can't vanish before being read.
+    pub fn auto_dismiss_after(&self) -> Option<Duration> {
+        match self {
+            NotificationLevel::Success => Some(Duration::from_secs(4)),
+            NotificationLevel::Info => Some(Duration::from_secs(5)),
+            NotificationLevel::Warning => Some(Duration::from_secs(8)),
+            NotificationLevel::Error => None,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+pub const MAX_RATING: u8 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StarFill {
+    Full,
+    Half,
+    Empty,
+}
+
+impl StarFill {
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            StarFill::Full => "star-full",
+            StarFill::Half => "star-half",
+            StarFill::Empty => "star-empty",
+        }
+    }
+}
+
+// How each of the five stars is filled for a rating, rounded to the nearest
+// half star so averages like 3.7 read as three and a half.
+pub fn star_fills(rating: f64) -> [StarFill; MAX_RATING as usize] {
+    let halves = (rating.clamp(0.0, MAX_RATING as f64) * 2.0).round() as usize;
+    std::array::from_fn(|i| match halves.saturating_sub(i * 2) {
+        0 => StarFill::Empty,
+        1 => StarFill::Half,
+        _ => StarFill::Full,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use StarFill::{Empty, Full, Half};
+
+    #[test]
+    fn whole_ratings_fill_whole_stars() {
+        assert_eq!(star_fills(0.0), [Empty; 5]);
+        assert_eq!(star_fills(3.0), [Full, Full, Full, Empty, Empty]);
+        assert_eq!(star_fills(5.0), [Full; 5]);
+    }
+
+    #[test]
+    fn averages_round_to_the_nearest_half_star() {
+        assert_eq!(star_fills(3.7), [Full, Full, Full, Half, Empty]);
+        assert_eq!(star_fills(3.2), [Full, Full, Full, Empty, Empty]);
+        assert_eq!(star_fills(3.8), [Full, Full, Full, Full, Empty]);
+        assert_eq!(star_fills(0.3), [Half, Empty, Empty, Empty, Empty]);
+    }
+
+    #[test]
+    fn out_of_range_ratings_are_clamped() {
+        assert_eq!(star_fills(-2.0), [Empty; 5]);
+        assert_eq!(star_fills(7.5), [Full; 5]);
+    }
+}
@@ -0,0 +1,160 @@
+use super::score::ScoreRange;
+use super::search::SearchResult;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RerankWeights {
+    pub relevance: f64,
+    pub rating: f64,
+    pub length: f64,
+    pub recency: f64,
+}
+
+impl Default for RerankWeights {
+    fn default() -> Self {
+        Self {
+            relevance: 1.0,
+            rating: 0.5,
+            length: 0.2,
+            recency: 0.0,
+        }
+    }
+}
+
+impl RerankWeights {
+    fn total(&self) -> f64 {
+        self.relevance + self.rating + self.length + self.recency
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RankedResult {
+    pub original_rank: usize,
+    pub combined: f64,
+    pub result: SearchResult,
+}
+
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    if max - min <= f64::EPSILON {
+        1.0
+    } else {
+        (value - min) / (max - min)
+    }
+}
+
+// Every signal is rescaled to 0..1 within the result set before weighting, so
+// the weights stay comparable regardless of the backend's distance units.
+pub fn rerank(results: &[SearchResult], weights: RerankWeights) -> Vec<RankedResult> {
+    let range = ScoreRange::from_scores(results.iter().map(|r| r.score as f64));
+    let max_len = results.iter().map(|r| r.review_body.chars().count()).max().unwrap_or(0) as f64;
+    let created: Vec<i64> = results.iter().filter_map(|r| r.created_at).collect();
+    let oldest = created.iter().copied().min().unwrap_or(0) as f64;
+    let newest = created.iter().copied().max().unwrap_or(0) as f64;
+
+    let weights = if weights.total() > 0.0 {
+        weights
+    } else {
+        RerankWeights { relevance: 1.0, rating: 0.0, length: 0.0, recency: 0.0 }
+    };
+    let total = weights.total();
+
+    let mut ranked: Vec<RankedResult> = results
+        .iter()
+        .enumerate()
+        .map(|(idx, result)| {
+            let relevance = range.map_or(1.0, |r| r.relevance(result.score as f64) as f64 / 100.0);
+            let rating = (result.review_rating.clamp(1, 5) - 1) as f64 / 4.0;
+            let length = if max_len > 0.0 {
+                result.review_body.chars().count() as f64 / max_len
+            } else {
+                0.0
+            };
+            let recency = result
+                .created_at
+                .map_or(0.0, |t| normalize(t as f64, oldest, newest));
+
+            let combined = (weights.relevance * relevance
+                + weights.rating * rating
+                + weights.length * length
+                + weights.recency * recency)
+                / total;
+
+            RankedResult {
+                original_rank: idx,
+                combined,
+                result: result.clone(),
+            }
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.combined.total_cmp(&a.combined));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(score: f32, rating: i32, body: &str, created_at: Option<i64>) -> SearchResult {
+        SearchResult {
+            review_title: String::new(),
+            review_body: body.to_string(),
+            product_id: "B001".to_string(),
+            review_rating: rating,
+            score,
+            created_at,
+        }
+    }
+
+    fn order(ranked: &[RankedResult]) -> Vec<usize> {
+        ranked.iter().map(|r| r.original_rank).collect()
+    }
+
+    #[test]
+    fn zero_weights_fall_back_to_relevance() {
+        let results = [result(0.5, 5, "", None), result(0.1, 1, "", None), result(0.3, 3, "", None)];
+        let none = RerankWeights { relevance: 0.0, rating: 0.0, length: 0.0, recency: 0.0 };
+
+        let ranked = rerank(&results, none);
+        assert_eq!(order(&ranked), vec![1, 2, 0]);
+        assert_eq!(ranked[0].combined, 1.0);
+        assert_eq!(ranked[2].combined, 0.0);
+    }
+
+    #[test]
+    fn missing_created_at_ranks_with_the_oldest() {
+        let results = [
+            result(0.1, 3, "", None),
+            result(0.1, 3, "", Some(100)),
+            result(0.1, 3, "", Some(300)),
+            result(0.1, 3, "", Some(200)),
+        ];
+        let recency = RerankWeights { relevance: 0.0, rating: 0.0, length: 0.0, recency: 1.0 };
+
+        let ranked = rerank(&results, recency);
+        assert_eq!(order(&ranked), vec![2, 3, 0, 1]);
+        assert_eq!(ranked[2].combined, ranked[3].combined);
+    }
+
+    #[test]
+    fn weights_change_the_order() {
+        let results = [
+            result(0.1, 1, "short", None),
+            result(0.2, 5, "a much longer review body", None),
+            result(0.3, 3, "medium body", None),
+        ];
+        let by_relevance = RerankWeights { relevance: 1.0, rating: 0.0, length: 0.0, recency: 0.0 };
+        let by_rating = RerankWeights { relevance: 0.0, rating: 1.0, length: 0.0, recency: 0.0 };
+        let by_length = RerankWeights { relevance: 0.0, rating: 0.0, length: 1.0, recency: 0.0 };
+
+        assert_eq!(order(&rerank(&results, by_relevance)), vec![0, 1, 2]);
+        assert_eq!(order(&rerank(&results, by_rating)), vec![1, 2, 0]);
+        assert_eq!(order(&rerank(&results, by_length)), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn ties_keep_the_backend_order() {
+        let results = [result(0.2, 4, "", None), result(0.2, 4, "", None)];
+
+        assert_eq!(order(&rerank(&results, RerankWeights::default())), vec![0, 1]);
+    }
+}
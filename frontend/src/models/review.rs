@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use super::index::default_index;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Review {
@@ -14,4 +15,6 @@ pub struct ReviewInput {
     pub review_body: String,
     pub product_id: String,
     pub review_rating: i32,
+    #[serde(default = "default_index")]
+    pub index: String,
 }
\ No newline at end of file
@@ -0,0 +1,71 @@
+// Maps a raw SPFresh distance (lower is closer) onto a fixed 0..=100 scale:
+// 0 is 100%, 1 is 50% and 3 is 25%. It doesn't depend on the other results,
+// so a cutoff keeps the same meaning from one search to the next.
+pub fn relevance(distance: f64) -> u8 {
+    if !distance.is_finite() {
+        return 0;
+    }
+    (100.0 / (1.0 + distance.max(0.0))).round() as u8
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreRange {
+    pub best: f64,
+    pub worst: f64,
+}
+
+impl ScoreRange {
+    pub fn from_scores(scores: impl IntoIterator<Item = f64>) -> Option<Self> {
+        scores
+            .into_iter()
+            .filter(|s| s.is_finite())
+            .fold(None, |range, score| match range {
+                None => Some(ScoreRange { best: score, worst: score }),
+                Some(r) => Some(ScoreRange {
+                    best: r.best.min(score),
+                    worst: r.worst.max(score),
+                }),
+            })
+    }
+
+    pub fn relevance(&self, score: f64) -> u8 {
+        if !score.is_finite() {
+            return 0;
+        }
+        let spread = self.worst - self.best;
+        if spread <= f64::EPSILON {
+            return 100;
+        }
+        (((self.worst - score) / spread) * 100.0).round().clamp(0.0, 100.0) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relevance_uses_a_fixed_scale() {
+        assert_eq!(relevance(0.0), 100);
+        assert_eq!(relevance(1.0), 50);
+        assert_eq!(relevance(3.0), 25);
+        assert_eq!(relevance(0.25), 80);
+        assert_eq!(relevance(1_000_000.0), 0);
+    }
+
+    #[test]
+    fn missing_and_negative_distances() {
+        assert_eq!(relevance(f64::INFINITY), 0);
+        assert_eq!(relevance(f64::NAN), 0);
+        assert_eq!(relevance(-0.5), 100);
+    }
+
+    #[test]
+    fn score_range_skips_missing_scores() {
+        let range = ScoreRange::from_scores([f64::INFINITY, 0.4, 0.1]).unwrap();
+        assert_eq!(range, ScoreRange { best: 0.1, worst: 0.4 });
+        assert_eq!(range.relevance(0.1), 100);
+        assert_eq!(range.relevance(0.4), 0);
+        assert_eq!(ScoreRange::from_scores([f64::INFINITY]), None);
+    }
+}
@@ -1,22 +1,71 @@
-use serde::{Deserialize, Serialize};
-use super::Review;
+use serde::{Deserialize, Deserializer, Serialize};
+use super::index::default_index;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchQuery {
     pub query: String,
     pub k: usize,
+    #[serde(default = "default_index")]
+    pub index: String,
 }
 
+// Older reviews can lack any of these, so a missing or null field falls back
+// to a default instead of failing the whole response. The views show the
+// fallbacks as "Untitled", "No content" and "N/A".
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
+    #[serde(default, deserialize_with = "null_as_default")]
     pub review_title: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub review_body: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub product_id: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub review_rating: i32,
+    // Scores are distances, so an unknown one sorts after every real match.
+    #[serde(default = "missing_score", deserialize_with = "score_or_missing")]
     pub score: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResponse {
     pub results: Vec<SearchResult>,
-}
\ No newline at end of file
+}
+
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+fn missing_score() -> f32 {
+    f32::INFINITY
+}
+
+fn score_or_missing<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    Ok(Option::<f32>::deserialize(deserializer)?.unwrap_or_else(missing_score))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_and_null_fields_fall_back() {
+        let response: SearchResponse = serde_json::from_str(
+            r#"{"results": [{"review_title": null, "product_id": "B001", "score": null}]}"#,
+        )
+        .unwrap();
+        let result = &response.results[0];
+
+        assert_eq!(result.review_title, "");
+        assert_eq!(result.review_body, "");
+        assert_eq!(result.product_id, "B001");
+        assert_eq!(result.review_rating, 0);
+        assert_eq!(result.score, f32::INFINITY);
+    }
+}
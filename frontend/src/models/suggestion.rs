@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PopularQuery {
+    pub query: String,
+    #[serde(default)]
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestionSource {
+    Saved,
+    History,
+    Popular,
+}
+
+impl SuggestionSource {
+    // Message catalog key for the badge shown next to the suggestion.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SuggestionSource::Saved => "suggest.saved",
+            SuggestionSource::History => "suggest.history",
+            SuggestionSource::Popular => "suggest.popular",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuerySuggestion {
+    pub text: String,
+    pub source: SuggestionSource,
+}
+
+// Byte length of the part of `text` that case-insensitively matches all of
+// `prefix`, or `None` if `text` doesn't start with it.
+pub fn matched_prefix_len(text: &str, prefix: &str) -> Option<usize> {
+    let mut rest = text.chars();
+    let mut len = 0;
+    for p in prefix.chars() {
+        let c = rest.next()?;
+        if !c.to_lowercase().eq(p.to_lowercase()) {
+            return None;
+        }
+        len += c.len_utf8();
+    }
+    Some(len)
+}
+
+// Saved searches first, then recent ones, then what other users search for,
+// keeping only the first occurrence of each query and leaving out the one
+// already typed.
+pub fn merge_suggestions(
+    prefix: &str,
+    saved: &[String],
+    history: &[String],
+    popular: &[PopularQuery],
+    limit: usize,
+) -> Vec<QuerySuggestion> {
+    let prefix = prefix.trim_start();
+    let candidates = saved
+        .iter()
+        .map(|q| (q.as_str(), SuggestionSource::Saved))
+        .chain(history.iter().map(|q| (q.as_str(), SuggestionSource::History)))
+        .chain(popular.iter().map(|p| (p.query.as_str(), SuggestionSource::Popular)));
+
+    let mut merged: Vec<QuerySuggestion> = Vec::new();
+    for (text, source) in candidates {
+        if merged.len() == limit {
+            break;
+        }
+        let Some(matched) = matched_prefix_len(text, prefix) else { continue };
+        let seen = merged.iter().any(|s| s.text.to_lowercase() == text.to_lowercase());
+        if matched < text.len() && !seen {
+            merged.push(QuerySuggestion { text: text.to_string(), source });
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn popular(list: &[&str]) -> Vec<PopularQuery> {
+        list.iter().map(|q| PopularQuery { query: q.to_string(), count: 1 }).collect()
+    }
+
+    #[test]
+    fn prefix_matches_ignore_case_and_count_bytes() {
+        assert_eq!(matched_prefix_len("Great sound", "great"), Some(5));
+        assert_eq!(matched_prefix_len("Ünicode", "ü"), Some(2));
+        assert_eq!(matched_prefix_len("anything", ""), Some(0));
+        assert_eq!(matched_prefix_len("good", "great"), None);
+        assert_eq!(matched_prefix_len("gr", "great"), None);
+    }
+
+    #[test]
+    fn sources_merge_in_order_without_duplicates() {
+        let merged = merge_suggestions(
+            "ba",
+            &strings(&["battery life"]),
+            &strings(&["Battery Life", "bass", "cheap"]),
+            &popular(&["bass boost", "bass"]),
+            10,
+        );
+
+        let texts: Vec<(&str, SuggestionSource)> = merged.iter().map(|s| (s.text.as_str(), s.source)).collect();
+        assert_eq!(
+            texts,
+            vec![
+                ("battery life", SuggestionSource::Saved),
+                ("bass", SuggestionSource::History),
+                ("bass boost", SuggestionSource::Popular),
+            ]
+        );
+    }
+
+    #[test]
+    fn the_typed_query_and_the_limit_are_respected() {
+        let history = strings(&["bass", "bass boost", "bassline", "bass guitar"]);
+        let merged = merge_suggestions("  bass", &[], &history, &[], 2);
+
+        let texts: Vec<&str> = merged.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["bass boost", "bassline"]);
+    }
+}
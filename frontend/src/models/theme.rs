@@ -0,0 +1,46 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    // Follows the operating system's `prefers-color-scheme`.
+    #[default]
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 4] = [Theme::System, Theme::Light, Theme::Dark, Theme::HighContrast];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Theme::System => "system",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::HighContrast => "high-contrast",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Theme::ALL.into_iter().find(|t| t.code() == code)
+    }
+
+    // Class set on `<html>`; the system theme leaves it to the media query.
+    pub fn root_class(&self) -> &'static str {
+        match self {
+            Theme::System => "",
+            Theme::Light => "theme-light",
+            Theme::Dark => "theme-dark",
+            Theme::HighContrast => "theme-high-contrast",
+        }
+    }
+
+    // Message catalog key for the theme name.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Theme::System => "theme.system",
+            Theme::Light => "theme.light",
+            Theme::Dark => "theme.dark",
+            Theme::HighContrast => "theme.high_contrast",
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::time::Duration;
+use leptos::*;
+use leptos_router::A;
+use crate::controllers::api::{
+    create_snapshot, get_index_stats, get_job, invalidate_search_cache, list_snapshots, restore_snapshot,
+    start_compaction, start_reindex,
+};
+use crate::controllers::error_reporting::report_api_error;
+use crate::controllers::retry::ApiError;
+use crate::models::admin::{format_bytes, Job, JobKind, JobStatus};
+use super::{use_current_index, use_i18n, use_notifications, I18n, RequireAuth};
+
+const JOB_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_POLL_FAILURES: u32 = 5;
+
+fn format_timestamp(i18n: I18n, seconds: i64) -> String {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(seconds as f64 * 1000.0));
+    String::from(date.to_locale_string(i18n.locale.get().code(), &wasm_bindgen::JsValue::UNDEFINED))
+}
+
+// Polls until the job finishes. Stops quietly if another job replaced this
+// one or the page was left (the signal is disposed with it), and hands the
+// error to `on_lost` once polling is hopeless.
+fn poll_job(
+    job_id: String,
+    job: RwSignal<Option<Job>>,
+    failures: u32,
+    on_finished: impl Fn() + Clone + 'static,
+    on_lost: impl Fn(ApiError) + Clone + 'static,
+) {
+    set_timeout(
+        move || {
+            let still_current = job
+                .try_get_untracked()
+                .flatten()
+                .is_some_and(|j| j.id == job_id);
+            if !still_current {
+                return;
+            }
+            spawn_local(async move {
+                match get_job(&job_id).await {
+                    Ok(latest) => {
+                        let finished = latest.status.is_finished();
+                        job.try_set(Some(latest));
+                        if finished {
+                            on_finished();
+                        } else {
+                            poll_job(job_id, job, 0, on_finished, on_lost);
+                        }
+                    }
+                    // A transient failure shouldn't abandon a long-running job,
+                    // but a rejected request (e.g. an unknown job) won't recover.
+                    Err(e) if matches!(e, ApiError::Http { .. }) || failures + 1 >= MAX_POLL_FAILURES => on_lost(e),
+                    Err(_) => poll_job(job_id, job, failures + 1, on_finished, on_lost),
+                }
+            });
+        },
+        JOB_POLL_INTERVAL,
+    );
+}
+
+#[component]
+fn JobProgress(job: Job) -> impl IntoView {
+    let i18n = use_i18n();
+    let percent = job.percent();
+    let Job { kind, status, message, .. } = job;
+    let status_label = move || match status {
+        JobStatus::Queued => i18n.t("job.queued"),
+        JobStatus::Running => percent.map_or_else(|| i18n.t("job.running"), |p| format!("{}%", p)),
+        JobStatus::Succeeded => i18n.t("job.done"),
+        JobStatus::Failed => i18n.t("job.failed"),
+    };
+
+    view! {
+        <div class=format!("job-progress {}", status.css_class())>
+            <div class="job-header">
+                <strong>{move || i18n.t(kind.label_key())}</strong>
+                <span class="meta">{status_label}</span>
+            </div>
+            <div
+                class="job-bar"
+                role="progressbar"
+                aria-valuemin="0"
+                aria-valuemax="100"
+                aria-valuenow=percent.map(|p| p.to_string())
+            >
+                <div
+                    class="job-bar-fill"
+                    style=format!("width: {}%", if status.is_finished() { 100 } else { percent.unwrap_or(0) })
+                ></div>
+            </div>
+            {message.map(|m| view! { <p class="meta">{m}</p> })}
+        </div>
+    }
+}
+
+#[component]
+fn AdminConsole() -> impl IntoView {
+    let index = use_current_index();
+    let i18n = use_i18n();
+    let (reload, set_reload) = create_signal(0u32);
+    // Admin data is never needed for the first paint, so fetch it in the browser only.
+    let stats = create_local_resource(move || (index.get(), reload.get()), |(index, _)| async move {
+        get_index_stats(&index).await
+    });
+    let snapshots = create_local_resource(move || (index.get(), reload.get()), |(index, _)| async move {
+        list_snapshots(&index).await
+    });
+    let job = create_rw_signal(None::<Job>);
+    let notifications = use_notifications();
+    let job_running = move || job.get().is_some_and(|j| !j.status.is_finished());
+
+    let start = move |kind: JobKind, snapshot_id: Option<String>| {
+        let index = index.get_untracked();
+        spawn_local(async move {
+            let started = match kind {
+                JobKind::Reindex => start_reindex(&index).await,
+                JobKind::Compact => start_compaction(&index).await,
+                JobKind::Snapshot => create_snapshot(&index).await,
+                JobKind::Restore => match snapshot_id {
+                    Some(id) => restore_snapshot(&index, &id).await,
+                    None => return,
+                },
+            };
+            match started {
+                Ok(started) => {
+                    let job_id = started.id.clone();
+                    let finished = started.status.is_finished();
+                    job.set(Some(started));
+                    let on_finished = move || {
+                        // Any finished job, even a failed one, may have changed what searches return.
+                        invalidate_search_cache();
+                        set_reload.update(|n| *n += 1);
+                        let Some(job) = job.try_get_untracked().flatten() else { return };
+                        let args = [("job", i18n.t(job.kind.label_key()))];
+                        if job.status == JobStatus::Failed {
+                            notifications.error(i18n.tf("job.failed_notice", &args));
+                        } else {
+                            notifications.success(i18n.tf("job.finished_notice", &args));
+                        }
+                    };
+                    // The job may still be running; it just can't be followed from here.
+                    let on_lost = move |e: ApiError| {
+                        report_api_error(&e);
+                        let Some(lost) = job.try_get_untracked().flatten() else { return };
+                        let args = [("job", i18n.t(lost.kind.label_key())), ("message", e.to_string())];
+                        notifications.error(i18n.tf("job.lost_notice", &args));
+                        job.set(None);
+                    };
+                    if finished {
+                        on_finished();
+                    } else {
+                        poll_job(job_id, job, 0, on_finished, on_lost);
+                    }
+                }
+                Err(e) => {
+                    report_api_error(&e);
+                    notifications.error(i18n.tf("error.generic", &[("message", e.to_string())]));
+                }
+            }
+        });
+    };
+
+    let on_restore = move |snapshot_id: String| {
+        let confirmed = web_sys::window()
+            .and_then(|w| {
+                w.confirm_with_message(&i18n.tf("admin.confirm_restore", &[("id", snapshot_id.clone())]))
+                    .ok()
+            })
+            .unwrap_or(false);
+        if confirmed {
+            start(JobKind::Restore, Some(snapshot_id));
+        }
+    };
+
+    view! {
+        <div class="container admin-console">
+            <h2>{move || i18n.tf("admin.heading", &[("index", index.get())])}</h2>
+            <Suspense fallback=move || view! { <p class="meta">{i18n.t("admin.loading_stats")}</p> }>
+                {move || stats.get().map(|res| match res {
+                    Ok(stats) => view! {
+                        <dl class="admin-stats">
+                            <dt>{i18n.t("admin.documents")}</dt>
+                            <dd>{i18n.number(stats.document_count as f64, 0)}</dd>
+                            <dt>{i18n.t("admin.deleted")}</dt>
+                            <dd>{i18n.number(stats.deleted_count as f64, 0)}</dd>
+                            <dt>{i18n.t("admin.size")}</dt>
+                            <dd>{format_bytes(stats.size_bytes)}</dd>
+                            <dt>{i18n.t("admin.dimensions")}</dt>
+                            <dd>{stats.dimensions.map_or_else(|| "–".to_string(), |d| d.to_string())}</dd>
+                            <dt>{i18n.t("admin.last_compacted")}</dt>
+                            <dd>
+                                {stats.last_compacted_at
+                                    .map_or_else(|| i18n.t("admin.never"), |at| format_timestamp(i18n, at))}
+                            </dd>
+                            <dt>{i18n.t("admin.last_snapshot")}</dt>
+                            <dd>
+                                {stats.last_snapshot_at
+                                    .map_or_else(|| i18n.t("admin.never"), |at| format_timestamp(i18n, at))}
+                            </dd>
+                        </dl>
+                    }.into_view(),
+                    Err(e) => view! {
+                        <div class="error">{i18n.tf("error.generic", &[("message", e.to_string())])}</div>
+                    }.into_view(),
+                })}
+            </Suspense>
+
+            <div class="admin-actions">
+                <button type="button" disabled=job_running on:click=move |_| start(JobKind::Reindex, None)>
+                    {move || i18n.t("admin.reindex")}
+                </button>
+                <button type="button" disabled=job_running on:click=move |_| start(JobKind::Compact, None)>
+                    {move || i18n.t("admin.compact")}
+                </button>
+                <button type="button" disabled=job_running on:click=move |_| start(JobKind::Snapshot, None)>
+                    {move || i18n.t("admin.create_snapshot")}
+                </button>
+            </div>
+
+            {move || job.get().map(|job| view! { <JobProgress job=job/> })}
+
+            <h2>{move || i18n.t("admin.snapshots")}</h2>
+            <Suspense fallback=move || view! { <p class="meta">{i18n.t("admin.loading_snapshots")}</p> }>
+                {move || snapshots.get().map(|res| match res {
+                    Ok(list) if list.is_empty() => view! {
+                        <p class="meta">{i18n.t("admin.no_snapshots")}</p>
+                    }.into_view(),
+                    Ok(list) => view! {
+                        <ul class="snapshot-list">
+                            {list.into_iter().map(|snapshot| {
+                                let id = snapshot.id.clone();
+                                view! {
+                                    <li>
+                                        <span>
+                                            <strong>{snapshot.id}</strong>
+                                            <span class="meta">
+                                                " " {format_timestamp(i18n, snapshot.created_at)}
+                                                " · " {format_bytes(snapshot.size_bytes)}
+                                            </span>
+                                        </span>
+                                        <button
+                                            type="button"
+                                            class="link-button"
+                                            disabled=job_running
+                                            on:click=move |_| on_restore(id.clone())
+                                        >
+                                            {i18n.t("admin.restore")}
+                                        </button>
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ul>
+                    }.into_view(),
+                    Err(e) => view! {
+                        <div class="error">{i18n.tf("error.generic", &[("message", e.to_string())])}</div>
+                    }.into_view(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+pub fn AdminPage() -> impl IntoView {
+    let i18n = use_i18n();
+
+    // Any signed-in user gets the console; admin rights are the backend's call.
+    view! {
+        <A href="/">{move || i18n.t("product.back")}</A>
+        <RequireAuth>
+            <AdminConsole/>
+        </RequireAuth>
+    }
+}
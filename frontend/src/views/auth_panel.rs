@@ -0,0 +1,182 @@
+use std::time::Duration;
+use leptos::*;
+use crate::controllers::auth::{load_session, login, logout, now_ms, refresh};
+use crate::models::auth::AuthSession;
+use crate::models::notification::NotificationLevel;
+use super::{use_i18n, use_notifications, Toast};
+
+// Refresh this long before the access token expires so in-flight requests
+// never go out with a stale token.
+const REFRESH_MARGIN_MS: f64 = 60_000.0;
+// Browsers fire longer timeouts immediately, so long-lived tokens are
+// checked again after this instead.
+const MAX_TIMER_MS: f64 = i32::MAX as f64;
+
+#[derive(Clone, Copy)]
+pub struct AuthContext {
+    pub session: RwSignal<Option<AuthSession>>,
+}
+
+impl AuthContext {
+    pub fn is_authenticated(&self) -> bool {
+        self.session.with(|s| s.as_ref().is_some_and(|s| !s.is_expired(now_ms())))
+    }
+}
+
+// Refreshes the token shortly before it expires. A token that can't be
+// refreshed signs the user out when it runs out instead, rather than leaving
+// the forms up while requests silently go out without it.
+fn schedule_refresh(session: RwSignal<Option<AuthSession>>) {
+    let Some(current) = session.get_untracked() else { return };
+    let can_refresh = current.refresh_token.is_some();
+    let due_at = if can_refresh { current.expires_at - REFRESH_MARGIN_MS } else { current.expires_at };
+    let delay = (due_at - now_ms()).clamp(0.0, MAX_TIMER_MS);
+
+    set_timeout(
+        move || {
+            // Skip if the user signed out or logged in again in the meantime.
+            if session.get_untracked().as_ref() != Some(&current) {
+                return;
+            }
+            if now_ms() < due_at {
+                schedule_refresh(session);
+                return;
+            }
+            if !can_refresh {
+                logout();
+                session.set(None);
+                return;
+            }
+            spawn_local(async move {
+                match refresh(&current).await {
+                    Ok(refreshed) => {
+                        session.set(Some(refreshed));
+                        schedule_refresh(session);
+                    }
+                    Err(_) => {
+                        logout();
+                        session.set(None);
+                    }
+                }
+            });
+        },
+        Duration::from_millis(delay as u64),
+    );
+}
+
+pub fn provide_auth() -> AuthContext {
+    let ctx = AuthContext {
+        session: create_rw_signal(None),
+    };
+    // Session storage only exists in the browser; server renders start anonymous.
+    create_effect(move |_| {
+        ctx.session.set(load_session());
+        schedule_refresh(ctx.session);
+    });
+    provide_context(ctx);
+    ctx
+}
+
+pub fn use_is_authenticated() -> Signal<bool> {
+    let ctx = use_context::<AuthContext>();
+    Signal::derive(move || ctx.is_some_and(|c| c.is_authenticated()))
+}
+
+#[component]
+pub fn LoginPanel() -> impl IntoView {
+    let ctx = use_context::<AuthContext>().unwrap_or_else(provide_auth);
+    let i18n = use_i18n();
+    let (username, set_username) = create_signal(String::new());
+    let (password, set_password) = create_signal(String::new());
+    let notifications = use_notifications();
+    let (is_logging_in, set_is_logging_in) = create_signal(false);
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        set_is_logging_in.set(true);
+        notifications.dismiss_source("auth");
+
+        let user = username.get();
+        let pass = password.get();
+
+        spawn_local(async move {
+            match login(user, pass).await {
+                Ok(session) => {
+                    ctx.session.set(Some(session));
+                    schedule_refresh(ctx.session);
+                    set_password.set(String::new());
+                }
+                Err(e) => {
+                    notifications.push(
+                        Toast::new(NotificationLevel::Error, i18n.tf("error.generic", &[("message", e.to_string())]))
+                            .from_source("auth"),
+                    );
+                }
+            }
+            set_is_logging_in.set(false);
+        });
+    };
+
+    let on_logout = move |_| {
+        logout();
+        ctx.session.set(None);
+        notifications.push(Toast::new(NotificationLevel::Info, i18n.t("auth.signed_out")).from_source("auth"));
+    };
+
+    view! {
+        <div class="auth-panel">
+            {move || match ctx.session.get() {
+                Some(session) => view! {
+                    <div class="auth-signed-in">
+                        {i18n.t("auth.signed_in_as")} " " <strong>{session.username}</strong>
+                        <button type="button" class="link-button" on:click=on_logout>{i18n.t("auth.sign_out")}</button>
+                    </div>
+                }.into_view(),
+                None => view! {
+                    <form class="auth-form" on:submit=on_submit>
+                        <input
+                            type="text"
+                            placeholder=move || i18n.t("auth.username")
+                            aria-label=move || i18n.t("auth.username")
+                            autocomplete="username"
+                            prop:value=move || username.get()
+                            on:input=move |ev| set_username.set(event_target_value(&ev))
+                            required
+                        />
+                        <input
+                            type="password"
+                            placeholder=move || i18n.t("auth.password")
+                            aria-label=move || i18n.t("auth.password")
+                            autocomplete="current-password"
+                            prop:value=move || password.get()
+                            on:input=move |ev| set_password.set(event_target_value(&ev))
+                            required
+                        />
+                        <button type="submit" disabled=move || is_logging_in.get()>
+                            {move || i18n.t(if is_logging_in.get() { "auth.signing_in" } else { "auth.sign_in" })}
+                        </button>
+                    </form>
+                }.into_view(),
+            }}
+        </div>
+    }
+}
+
+#[component]
+pub fn RequireAuth(children: ChildrenFn) -> impl IntoView {
+    let is_authenticated = use_is_authenticated();
+    let i18n = use_i18n();
+
+    view! {
+        <Show
+            when=move || is_authenticated.get()
+            fallback=move || view! {
+                <div class="container">
+                    <p class="meta">{move || i18n.t("auth.required")}</p>
+                </div>
+            }
+        >
+            {children()}
+        </Show>
+    }
+}
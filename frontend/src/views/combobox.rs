@@ -0,0 +1,132 @@
+use std::time::Duration;
+use leptos::*;
+
+// Wait for a pause in typing before asking the backend.
+const LOOKUP_DELAY: Duration = Duration::from_millis(200);
+
+// Listbox state shared by the autocomplete inputs: the debounced text to look
+// up, whether the list is open and which option is highlighted. What the
+// suggestions are and what picking one does is up to each input.
+#[derive(Clone, Copy)]
+pub struct Combobox {
+    id: &'static str,
+    value: Signal<String>,
+    on_input: Callback<String>,
+    // Nothing is fetched until the input is first used.
+    pub lookup: RwSignal<Option<String>>,
+    pub open: RwSignal<bool>,
+    pub active: RwSignal<Option<usize>>,
+}
+
+pub fn use_combobox(id: &'static str, value: Signal<String>, on_input: Callback<String>) -> Combobox {
+    Combobox {
+        id,
+        value,
+        on_input,
+        lookup: create_rw_signal(None),
+        open: create_rw_signal(false),
+        active: create_rw_signal(None),
+    }
+}
+
+impl Combobox {
+    pub fn listbox_id(&self) -> String {
+        format!("{}-suggestions", self.id)
+    }
+
+    pub fn option_id(&self, i: usize) -> String {
+        format!("{}-option-{}", self.id, i)
+    }
+
+    // For `aria-activedescendant`, which must be empty while the list is hidden.
+    pub fn active_option_id(&self, expanded: bool) -> Option<String> {
+        self.active.get().filter(|_| expanded).map(|i| self.option_id(i))
+    }
+
+    pub fn choose(&self, text: String) {
+        self.on_input.call(text);
+        self.close();
+    }
+
+    pub fn close(&self) {
+        self.open.set(false);
+        self.active.set(None);
+    }
+
+    pub fn on_type(&self, ev: ev::Event) {
+        let typed = event_target_value(&ev);
+        self.on_input.call(typed.clone());
+        self.open.set(true);
+        self.active.set(None);
+        let (value, lookup) = (self.value, self.lookup);
+        set_timeout(
+            move || {
+                if value.try_get_untracked().is_some_and(|v| v == typed) {
+                    lookup.try_set(Some(typed.trim().to_string()));
+                }
+            },
+            LOOKUP_DELAY,
+        );
+    }
+
+    pub fn on_focus(&self) {
+        if self.lookup.get_untracked().is_none() {
+            self.lookup.set(Some(self.value.get_untracked().trim().to_string()));
+        }
+        self.open.set(true);
+    }
+
+    // Arrow keys move the highlight through `count` options and Escape closes
+    // the list. Enter on an open list returns the highlighted option, leaving
+    // it to the caller whether the key still submits the form.
+    pub fn on_keydown(&self, ev: &ev::KeyboardEvent, count: usize) -> Option<usize> {
+        match ev.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                ev.prevent_default();
+                self.open.set(true);
+                self.active.update(|a| *a = Some(a.map_or(0, |i| (i + 1) % count)));
+                None
+            }
+            "ArrowUp" if count > 0 => {
+                ev.prevent_default();
+                self.open.set(true);
+                self.active.update(|a| *a = Some(a.map_or(count - 1, |i| (i + count - 1) % count)));
+                None
+            }
+            "Enter" if self.open.get_untracked() => self.active.get_untracked().filter(|&i| i < count),
+            "Escape" if self.open.get_untracked() => {
+                ev.prevent_default();
+                self.close();
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[component]
+pub fn ComboboxOption(
+    combobox: Combobox,
+    index: usize,
+    #[prop(into)] on_pick: Callback<()>,
+    children: Children,
+) -> impl IntoView {
+    let is_active = move || combobox.active.get() == Some(index);
+
+    view! {
+        <li
+            id=combobox.option_id(index)
+            role="option"
+            class="autocomplete-option"
+            class:active=is_active
+            aria-selected=move || is_active().to_string()
+            // Before the input's blur closes the list.
+            on:mousedown=move |ev| {
+                ev.prevent_default();
+                on_pick.call(());
+            }
+        >
+            {children()}
+        </li>
+    }
+}
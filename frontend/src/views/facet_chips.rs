@@ -0,0 +1,85 @@
+use leptos::*;
+use crate::models::facets::{FacetFilter, ResultFacets};
+use super::use_i18n;
+
+#[component]
+pub fn FacetChips(
+    facets: Signal<ResultFacets>,
+    filter: ReadSignal<FacetFilter>,
+    set_filter: WriteSignal<FacetFilter>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let rating_chips = move || {
+        let ratings = facets.get().ratings;
+        (1..=5)
+            .rev()
+            .filter(|&r| ratings[(r - 1) as usize] > 0)
+            .map(|r| {
+                let count = ratings[(r - 1) as usize];
+                let active = move || filter.get().rating == Some(r);
+                view! {
+                    <button
+                        type="button"
+                        class="chip"
+                        class:active=active
+                        on:click=move |_| set_filter.update(|f| {
+                            f.rating = if f.rating == Some(r) { None } else { Some(r) };
+                        })
+                    >
+                        {r} "★ (" {count} ")"
+                    </button>
+                }
+            })
+            .collect_view()
+    };
+
+    let product_chips = move || {
+        facets
+            .get()
+            .top_products
+            .into_iter()
+            .map(|(product_id, count)| {
+                let id = product_id.clone();
+                let active = move || filter.get().product_id.as_deref() == Some(id.as_str());
+                let toggle_id = product_id.clone();
+                view! {
+                    <button
+                        type="button"
+                        class="chip"
+                        class:active=active
+                        on:click=move |_| {
+                            let id = toggle_id.clone();
+                            set_filter.update(|f| {
+                                f.product_id = if f.product_id.as_ref() == Some(&id) { None } else { Some(id) };
+                            });
+                        }
+                    >
+                        {i18n.tf("facets.product_chip", &[("id", product_id), ("count", i18n.number(count as f64, 0))])}
+                    </button>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class="facets">
+            <div class="facet-row">
+                <span class="facet-label">{move || i18n.t("facets.rating")}</span>
+                {rating_chips}
+            </div>
+            <div class="facet-row">
+                <span class="facet-label">{move || i18n.t("facets.top_products")}</span>
+                {product_chips}
+            </div>
+            <Show when=move || !filter.get().is_empty()>
+                <button
+                    type="button"
+                    class="link-button"
+                    on:click=move |_| set_filter.set(FacetFilter::default())
+                >
+                    {move || i18n.t("facets.clear")}
+                </button>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,73 @@
+use leptos::*;
+use crate::config::Config;
+use crate::controllers::storage::local_storage;
+use crate::models::index::DEFAULT_INDEX;
+use super::{use_i18n, AuthContext};
+
+#[derive(Clone, Copy)]
+pub struct IndexContext {
+    pub current: RwSignal<String>,
+}
+
+fn storage_key(username: Option<&str>) -> String {
+    format!("spfresh.index.{}", username.unwrap_or("anonymous"))
+}
+
+pub fn provide_index() -> IndexContext {
+    let ctx = IndexContext {
+        current: create_rw_signal(DEFAULT_INDEX.to_string()),
+    };
+    let auth = use_context::<AuthContext>();
+    let username = move || auth.and_then(|auth| auth.session.get().map(|s| s.username));
+
+    // Each user (or the anonymous visitor) keeps their own selection, so
+    // reload it whenever the signed-in user changes.
+    create_effect(move |_| {
+        let key = storage_key(username().as_deref());
+        let available = Config::get_available_indexes();
+        let stored = local_storage()
+            .and_then(|s| s.get_item(&key).ok().flatten())
+            .filter(|index| available.contains(index));
+        ctx.current.set(stored.unwrap_or_else(|| available[0].clone()));
+    });
+
+    provide_context(ctx);
+    ctx
+}
+
+pub fn use_current_index() -> Signal<String> {
+    let ctx = use_context::<IndexContext>();
+    Signal::derive(move || ctx.map_or_else(|| DEFAULT_INDEX.to_string(), |c| c.current.get()))
+}
+
+#[component]
+pub fn IndexSelector() -> impl IntoView {
+    let ctx = use_context::<IndexContext>().unwrap_or_else(provide_index);
+    let auth = use_context::<AuthContext>();
+    let indexes = Config::get_available_indexes();
+    let i18n = use_i18n();
+
+    let on_change = move |ev| {
+        let index = event_target_value(&ev);
+        let username = auth.and_then(|auth| auth.session.get_untracked().map(|s| s.username));
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(&storage_key(username.as_deref()), &index);
+        }
+        ctx.current.set(index);
+    };
+
+    view! {
+        <label class="index-selector">
+            {move || i18n.t("index.label")} " "
+            <select on:change=on_change prop:value=move || ctx.current.get()>
+                {indexes.into_iter().map(|index| {
+                    let selected = {
+                        let index = index.clone();
+                        move || ctx.current.get() == index
+                    };
+                    view! { <option value=index.clone() selected=selected>{index}</option> }
+                }).collect_view()}
+            </select>
+        </label>
+    }
+}
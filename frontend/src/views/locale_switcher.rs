@@ -0,0 +1,111 @@
+use leptos::*;
+use leptos_meta::Html;
+use crate::controllers::storage::local_storage;
+use crate::models::i18n::{format_number, interpolate, translate, Locale};
+
+const LOCALE_KEY: &str = "spfresh.locale";
+
+#[derive(Clone, Copy)]
+pub struct I18n {
+    pub locale: RwSignal<Locale>,
+}
+
+// Every lookup reads the locale signal, so text rendered inside a reactive
+// closure re-translates as soon as the user switches language.
+impl I18n {
+    pub fn t(&self, key: &str) -> String {
+        translate(self.locale.get(), key).to_string()
+    }
+
+    pub fn tf(&self, key: &str, args: &[(&str, String)]) -> String {
+        interpolate(translate(self.locale.get(), key), args)
+    }
+
+    // Picks `<key>.one` / `<key>.other` for the count and fills in `{count}`.
+    pub fn plural(&self, key: &str, count: usize, args: &[(&str, String)]) -> String {
+        let locale = self.locale.get();
+        let key = format!("{}.{}", key, locale.plural_category(count as u64).suffix());
+        let mut args = args.to_vec();
+        args.push(("count", format_number(locale, count as f64, 0)));
+        interpolate(translate(locale, &key), &args)
+    }
+
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        format_number(self.locale.get(), value, decimals)
+    }
+}
+
+fn initial_locale() -> Locale {
+    let stored = local_storage().and_then(|s| s.get_item(LOCALE_KEY).ok().flatten());
+    let browser = web_sys::window().and_then(|w| w.navigator().language());
+    stored
+        .or(browser)
+        .and_then(|code| Locale::from_code(&code))
+        .unwrap_or(Locale::En)
+}
+
+pub fn provide_i18n() -> I18n {
+    let i18n = I18n {
+        locale: create_rw_signal(Locale::En),
+    };
+    // Server renders are always English; the browser switches to the stored
+    // or preferred locale once it takes over.
+    create_effect(move |_| i18n.locale.set(initial_locale()));
+    provide_context(i18n);
+    i18n
+}
+
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>().unwrap_or_else(|| I18n {
+        locale: create_rw_signal(Locale::En),
+    })
+}
+
+#[component]
+pub fn LocaleSwitcher() -> impl IntoView {
+    let i18n = use_context::<I18n>().unwrap_or_else(provide_i18n);
+
+    let on_change = move |ev| {
+        let Some(locale) = Locale::from_code(&event_target_value(&ev)) else { return };
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(LOCALE_KEY, locale.code());
+        }
+        i18n.locale.set(locale);
+    };
+
+    view! {
+        <Html lang=move || i18n.locale.get().code()/>
+        <select
+            class="locale-switcher"
+            aria-label=move || i18n.t("locale.label")
+            on:change=on_change
+            prop:value=move || i18n.locale.get().code()
+        >
+            {Locale::ALL.into_iter().map(|locale| view! {
+                <option value=locale.code() selected=move || i18n.locale.get() == locale>
+                    {locale.native_name()}
+                </option>
+            }).collect_view()}
+        </select>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plural_picks_the_form_and_formats_the_count() {
+        let runtime = create_runtime();
+        let i18n = I18n { locale: create_rw_signal(Locale::En) };
+
+        assert_eq!(i18n.plural("search.found", 1, &[]), "✓ Found 1 result");
+        assert_eq!(i18n.plural("search.found", 0, &[]), "✓ Found 0 results");
+        assert_eq!(i18n.plural("search.found", 1200, &[]), "✓ Found 1,200 results");
+
+        i18n.locale.set(Locale::Th);
+        assert_eq!(i18n.plural("search.found", 1, &[]), "✓ พบ 1 รายการ");
+
+        runtime.dispose();
+    }
+}
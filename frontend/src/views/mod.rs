@@ -1,7 +1,51 @@
 pub mod app;
 pub mod search_view;
 pub mod review_form;
+pub mod relevance_bar;
+pub mod rerank_controls;
+pub mod result_card;
+pub mod product_group;
+pub mod facet_chips;
+pub mod status_bar;
+pub mod auth_panel;
+pub mod retry_countdown;
+pub mod telemetry_panel;
+pub mod product_page;
+pub mod index_selector;
+pub mod admin_page;
+pub mod locale_switcher;
+pub mod theme;
+pub mod notifications;
+pub mod result_list;
+pub mod star_rating;
+pub mod combobox;
+pub mod product_autocomplete;
+pub mod query_autocomplete;
+pub mod virtual_list;
+pub mod search_progress;
 
 pub use app::*;
 pub use search_view::*;
-pub use review_form::*;
\ No newline at end of file
+pub use review_form::*;
+pub use relevance_bar::*;
+pub use rerank_controls::*;
+pub use result_card::*;
+pub use product_group::*;
+pub use facet_chips::*;
+pub use status_bar::*;
+pub use auth_panel::*;
+pub use retry_countdown::*;
+pub use telemetry_panel::*;
+pub use product_page::*;
+pub use index_selector::*;
+pub use admin_page::*;
+pub use locale_switcher::*;
+pub use theme::*;
+pub use notifications::*;
+pub use result_list::*;
+pub use star_rating::*;
+pub use combobox::*;
+pub use product_autocomplete::*;
+pub use query_autocomplete::*;
+pub use virtual_list::*;
+pub use search_progress::*;
\ No newline at end of file
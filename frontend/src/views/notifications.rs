@@ -0,0 +1,177 @@
+use leptos::*;
+use crate::models::notification::NotificationLevel;
+use super::use_i18n;
+
+// Oldest toasts are dropped once this many are stacked up.
+const MAX_TOASTS: usize = 4;
+
+#[derive(Clone)]
+pub struct ToastAction {
+    // Message catalog key for the button label.
+    pub label_key: &'static str,
+    pub on_click: Callback<()>,
+}
+
+#[derive(Clone)]
+pub struct Toast {
+    pub id: u64,
+    pub level: NotificationLevel,
+    pub message: String,
+    pub action: Option<ToastAction>,
+    // Toasts from the same source replace each other instead of piling up,
+    // e.g. only the outcome of the latest search is shown.
+    pub source: Option<&'static str>,
+}
+
+impl Toast {
+    pub fn new(level: NotificationLevel, message: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            level,
+            message: message.into(),
+            action: None,
+            source: None,
+        }
+    }
+
+    pub fn with_action(mut self, label_key: &'static str, on_click: impl Fn() + 'static) -> Self {
+        self.action = Some(ToastAction {
+            label_key,
+            on_click: Callback::new(move |_| on_click()),
+        });
+        self
+    }
+
+    pub fn from_source(mut self, source: &'static str) -> Self {
+        self.source = Some(source);
+        self
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Notifications {
+    pub toasts: RwSignal<Vec<Toast>>,
+    next_id: StoredValue<u64>,
+}
+
+impl Notifications {
+    fn new() -> Self {
+        Self {
+            toasts: create_rw_signal(Vec::new()),
+            next_id: store_value(1),
+        }
+    }
+
+    pub fn push(&self, mut toast: Toast) -> u64 {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        toast.id = id;
+
+        let delay = toast.level.auto_dismiss_after();
+        self.toasts.update(|toasts| {
+            if let Some(source) = toast.source {
+                toasts.retain(|t| t.source != Some(source));
+            }
+            toasts.push(toast);
+            let overflow = toasts.len().saturating_sub(MAX_TOASTS);
+            toasts.drain(..overflow);
+        });
+
+        if let Some(delay) = delay {
+            let this = *self;
+            set_timeout(move || this.dismiss(id), delay);
+        }
+        id
+    }
+
+    pub fn success(&self, message: impl Into<String>) -> u64 {
+        self.push(Toast::new(NotificationLevel::Success, message))
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> u64 {
+        self.push(Toast::new(NotificationLevel::Error, message))
+    }
+
+    pub fn dismiss(&self, id: u64) {
+        // Timers can outlive the app that scheduled them.
+        self.toasts.try_update(|toasts| toasts.retain(|t| t.id != id));
+    }
+
+    // Clears a source's stale outcome when it starts over, like a new search.
+    pub fn dismiss_source(&self, source: &'static str) {
+        self.toasts.try_update(|toasts| toasts.retain(|t| t.source != Some(source)));
+    }
+}
+
+pub fn provide_notifications() -> Notifications {
+    let notifications = Notifications::new();
+    provide_context(notifications);
+    notifications
+}
+
+pub fn use_notifications() -> Notifications {
+    use_context::<Notifications>().unwrap_or_else(Notifications::new)
+}
+
+#[component]
+fn ToastItem(toast: Toast) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let Toast { id, level, message, action, .. } = toast;
+
+    view! {
+        <div class=format!("toast {}", level.css_class())>
+            <span class="toast-message">{message}</span>
+            {action.map(|ToastAction { label_key, on_click }| view! {
+                <button
+                    type="button"
+                    class="link-button toast-action"
+                    on:click=move |_| {
+                        notifications.dismiss(id);
+                        on_click.call(());
+                    }
+                >
+                    {move || i18n.t(label_key)}
+                </button>
+            })}
+            <button
+                type="button"
+                class="toast-dismiss"
+                aria-label=move || i18n.t("toast.dismiss")
+                on:click=move |_| notifications.dismiss(id)
+            >
+                "×"
+            </button>
+        </div>
+    }
+}
+
+// Like any live region, both groups must already be in the DOM before a toast
+// arrives for screen readers to announce it. Urgent toasts interrupt; the
+// rest wait for a pause.
+#[component]
+pub fn ToastStack() -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let toasts = move |urgent: bool| {
+        move || {
+            notifications
+                .toasts
+                .get()
+                .into_iter()
+                .filter(|t| t.level.is_urgent() == urgent)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    view! {
+        <div class="toast-stack" role="region" aria-label=move || i18n.t("toast.region")>
+            <div role="status" aria-live="polite">
+                <For each=toasts(false) key=|t| t.id children=move |toast| view! { <ToastItem toast=toast/> }/>
+            </div>
+            <div role="alert" aria-live="assertive">
+                <For each=toasts(true) key=|t| t.id children=move |toast| view! { <ToastItem toast=toast/> }/>
+            </div>
+        </div>
+    }
+}
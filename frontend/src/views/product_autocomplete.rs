@@ -0,0 +1,110 @@
+use leptos::*;
+use crate::controllers::api::suggest_products;
+use crate::models::product::ProductSummary;
+use super::{use_combobox, use_current_index, use_i18n, ComboboxOption, StarRating};
+
+// Text input for a product ID that suggests existing products as you type and
+// warns before a review would create a product nobody has reviewed yet.
+#[component]
+pub fn ProductIdInput(
+    id: &'static str,
+    #[prop(into)] value: Signal<String>,
+    #[prop(into)] on_input: Callback<String>,
+) -> impl IntoView {
+    let index = use_current_index();
+    let i18n = use_i18n();
+    let combobox = use_combobox(id, value, on_input);
+    let lookup = combobox.lookup;
+    let suggestions = create_local_resource(
+        move || lookup.get().map(|prefix| (prefix, index.get())),
+        |key| async move {
+            let (prefix, index) = key?;
+            suggest_products(prefix, index).await.ok()
+        },
+    );
+    let matches = move || suggestions.get().flatten().unwrap_or_default();
+    let show_list = move || combobox.open.get() && !matches().is_empty();
+
+    // Only once the lookup has caught up with what was typed; a failed lookup
+    // proves nothing, so it never warns. The backend lists an exact match
+    // first, so it can't be cut off by the suggestion limit.
+    let is_new_product = move || {
+        let typed = value.get().trim().to_string();
+        if typed.is_empty() || lookup.get().as_ref() != Some(&typed) || suggestions.loading().get() {
+            return false;
+        }
+        suggestions
+            .get()
+            .flatten()
+            .is_some_and(|list| list.iter().all(|p| p.product_id != typed))
+    };
+
+    let choose = move |product_id: String| {
+        lookup.set(Some(product_id.clone()));
+        combobox.choose(product_id);
+    };
+
+    // Without a highlighted suggestion Enter submits the form as usual.
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let products = matches();
+        if let Some(product) = combobox.on_keydown(&ev, products.len()).and_then(|i| products.get(i)) {
+            ev.prevent_default();
+            choose(product.product_id.clone());
+        }
+    };
+
+    let warning_id = format!("{}-new", id);
+
+    view! {
+        <div class="autocomplete">
+            <input
+                type="text"
+                id=id
+                role="combobox"
+                autocomplete="off"
+                aria-autocomplete="list"
+                aria-controls=combobox.listbox_id()
+                aria-expanded=move || show_list().to_string()
+                aria-activedescendant=move || combobox.active_option_id(show_list())
+                aria-describedby={
+                    let warning_id = warning_id.clone();
+                    move || is_new_product().then(|| warning_id.clone())
+                }
+                prop:value=move || value.get()
+                on:input=move |ev| combobox.on_type(ev)
+                on:focus=move |_| combobox.on_focus()
+                on:blur=move |_| combobox.open.set(false)
+                on:keydown=on_keydown
+                required
+            />
+            <ul
+                id=combobox.listbox_id()
+                class="autocomplete-list"
+                role="listbox"
+                aria-label=move || i18n.t("autocomplete.products")
+                hidden=move || !show_list()
+            >
+                {move || matches().into_iter().enumerate().map(|(i, product)| {
+                    let ProductSummary { product_id, review_count, average_rating } = product;
+                    let chosen = product_id.clone();
+                    view! {
+                        <ComboboxOption combobox=combobox index=i on_pick=move |_| choose(chosen.clone())>
+                            <span class="autocomplete-value">{product_id}</span>
+                            <span class="meta">
+                                <StarRating value=average_rating/> " "
+                                {i18n.plural("product.summary", review_count, &[("rating", i18n.number(average_rating, 1))])}
+                            </span>
+                        </ComboboxOption>
+                    }
+                }).collect_view()}
+            </ul>
+            <div role="status" aria-live="polite">
+                {move || is_new_product().then(|| view! {
+                    <small id=warning_id.clone() class="hint autocomplete-warning">
+                        {i18n.tf("autocomplete.new_product", &[("id", value.get().trim().to_string())])}
+                    </small>
+                })}
+            </div>
+        </div>
+    }
+}
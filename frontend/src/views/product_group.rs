@@ -0,0 +1,48 @@
+use leptos::*;
+use crate::models::grouping::ProductGroup;
+use super::{use_i18n, DisplayedResult, ResultCard, StarRating};
+
+// `members` are the group's own results, in `group.members` order (best first).
+#[component]
+pub fn ProductGroupCard(group: ProductGroup, members: Vec<DisplayedResult>) -> impl IntoView {
+    let i18n = use_i18n();
+    let (expanded, set_expanded) = create_signal(false);
+    let mut members = members.into_iter();
+    let Some(best) = members.next() else { return ().into_view() };
+    let others: Vec<DisplayedResult> = members.collect();
+    let other_count = others.len();
+    let review_count = other_count + 1;
+    let ProductGroup { product_id, average_rating: average, .. } = group;
+
+    view! {
+        <div class="product-group">
+            <div class="product-group-header">
+                <strong>{move || i18n.tf("product.heading", &[("id", product_id.clone())])}</strong>
+                <span class="meta">
+                    <StarRating value=average/> " "
+                    {move || i18n.plural("product.summary", review_count, &[("rating", i18n.number(average, 1))])}
+                </span>
+            </div>
+            <ResultCard item=best/>
+            <Show when=move || { other_count > 0 }>
+                <button
+                    type="button"
+                    class="link-button"
+                    on:click=move |_| set_expanded.update(|e| *e = !*e)
+                >
+                    {move || if expanded.get() {
+                        i18n.t("group.hide_others")
+                    } else {
+                        i18n.plural("group.show_more", other_count, &[])
+                    }}
+                </button>
+            </Show>
+            <Show when=move || expanded.get()>
+                <div class="product-group-others">
+                    {others.clone().into_iter().map(|item| view! { <ResultCard item=item/> }).collect_view()}
+                </div>
+            </Show>
+        </div>
+    }
+    .into_view()
+}
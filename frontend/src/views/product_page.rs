@@ -0,0 +1,55 @@
+use leptos::*;
+use leptos_router::{use_params_map, A};
+use crate::controllers::transport::fetch_product_reviews;
+use super::{use_current_index, use_i18n, StarRating};
+
+#[component]
+pub fn ProductPage() -> impl IntoView {
+    let params = use_params_map();
+    let product_id = move || params.with(|p| p.get("id").cloned().unwrap_or_default());
+    let index = use_current_index();
+    let i18n = use_i18n();
+    let reviews = create_resource(
+        move || (product_id(), index.get()),
+        |(id, index)| fetch_product_reviews(id, index),
+    );
+
+    view! {
+        <div class="container">
+            <A href="/">{move || i18n.t("product.back")}</A>
+            <h2>{move || i18n.tf("product.heading", &[("id", product_id())])}</h2>
+            <Suspense fallback=move || view! { <p class="meta">{i18n.t("product.loading")}</p> }>
+                {move || reviews.get().map(|res| match res {
+                    Ok(reviews) if reviews.is_empty() => view! {
+                        <p class="meta">{i18n.t("product.empty")}</p>
+                    }.into_view(),
+                    Ok(reviews) => {
+                        let count = reviews.len();
+                        let average = reviews.iter().map(|r| r.review_rating).sum::<i32>() as f64 / count as f64;
+                        view! {
+                            <p class="meta">
+                                <StarRating value=average/> " "
+                                {i18n.plural("product.summary", count, &[("rating", i18n.number(average, 1))])}
+                            </p>
+                            {reviews.into_iter().map(|review| {
+                                view! {
+                                    <div class="result-item">
+                                        <h3>{review.review_title}</h3>
+                                        <p><strong>{i18n.t("result.review")} " "</strong> {review.review_body}</p>
+                                        <p>
+                                            <strong>{i18n.t("result.rating")} " "</strong>
+                                            <StarRating value=review.review_rating as f64/>
+                                        </p>
+                                    </div>
+                                }
+                            }).collect_view()}
+                        }.into_view()
+                    }
+                    Err(e) => view! {
+                        <div class="error">{i18n.tf("error.generic", &[("message", e.to_string())])}</div>
+                    }.into_view(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
@@ -0,0 +1,141 @@
+use leptos::*;
+use crate::controllers::api::popular_queries;
+use crate::controllers::search_history::{load_history, load_saved_searches, record_history, toggle_saved_search};
+use crate::models::suggestion::{matched_prefix_len, merge_suggestions};
+use super::{use_combobox, use_current_index, use_i18n, ComboboxOption};
+
+const MAX_SUGGESTIONS: usize = 8;
+
+#[derive(Clone, Copy)]
+pub struct SearchHistory {
+    pub recent: RwSignal<Vec<String>>,
+    pub saved: RwSignal<Vec<String>>,
+}
+
+impl SearchHistory {
+    pub fn record(&self, query: &str) {
+        self.recent.set(record_history(query));
+    }
+
+    pub fn toggle_saved(&self, query: &str) {
+        self.saved.set(toggle_saved_search(query));
+    }
+
+    pub fn is_saved(&self, query: &str) -> bool {
+        let query = query.trim();
+        self.saved.with(|saved| saved.iter().any(|q| q == query))
+    }
+}
+
+// Both lists live in localStorage, so they are only loaded once the browser
+// takes over.
+pub fn use_search_history() -> SearchHistory {
+    let history = SearchHistory {
+        recent: create_rw_signal(Vec::new()),
+        saved: create_rw_signal(Vec::new()),
+    };
+    create_effect(move |_| {
+        history.recent.set(load_history());
+        history.saved.set(load_saved_searches());
+    });
+    history
+}
+
+// Search box with typeahead suggestions from saved searches, recent searches
+// and popular queries. Picking a suggestion fills the box and submits the
+// form; Enter without a highlighted suggestion submits whatever was typed.
+#[component]
+pub fn QueryInput(
+    id: &'static str,
+    #[prop(into)] value: Signal<String>,
+    #[prop(into)] on_input: Callback<String>,
+    history: SearchHistory,
+    #[prop(into)] placeholder: Signal<String>,
+) -> impl IntoView {
+    let index = use_current_index();
+    let i18n = use_i18n();
+    let input = create_node_ref::<html::Input>();
+    let combobox = use_combobox(id, value, on_input);
+    let lookup = combobox.lookup;
+    // Popular queries are optional: while they load, or if the backend doesn't
+    // offer them, the local suggestions are shown on their own.
+    let popular = create_local_resource(
+        move || lookup.get().map(|prefix| (prefix, index.get())),
+        |key| async move {
+            let (prefix, index) = key?;
+            popular_queries(prefix, index).await.ok()
+        },
+    );
+    let suggestions = move || {
+        let popular = popular.get().flatten().unwrap_or_default();
+        let typed = value.get();
+        history.saved.with(|saved| {
+            history
+                .recent
+                .with(|recent| merge_suggestions(&typed, saved, recent, &popular, MAX_SUGGESTIONS))
+        })
+    };
+    let show_list = move || combobox.open.get() && !suggestions().is_empty();
+
+    // The signal is updated before the form's submit handler runs, so letting
+    // Enter through searches for the picked suggestion.
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let options = suggestions();
+        if let Some(suggestion) = combobox.on_keydown(&ev, options.len()).and_then(|i| options.get(i)) {
+            combobox.choose(suggestion.text.clone());
+        }
+    };
+
+    let on_pick = move |text: String| {
+        combobox.choose(text);
+        if let Some(form) = input.get_untracked().and_then(|input| input.form()) {
+            let _ = form.request_submit();
+        }
+    };
+
+    view! {
+        <div class="autocomplete">
+            <input
+                type="text"
+                id=id
+                node_ref=input
+                role="combobox"
+                autocomplete="off"
+                aria-autocomplete="list"
+                aria-controls=combobox.listbox_id()
+                aria-expanded=move || show_list().to_string()
+                aria-activedescendant=move || combobox.active_option_id(show_list())
+                placeholder=move || placeholder.get()
+                prop:value=move || value.get()
+                on:input=move |ev| combobox.on_type(ev)
+                on:focus=move |_| combobox.on_focus()
+                on:blur=move |_| combobox.open.set(false)
+                on:keydown=on_keydown
+                required
+            />
+            <ul
+                id=combobox.listbox_id()
+                class="autocomplete-list"
+                role="listbox"
+                aria-label=move || i18n.t("suggest.label")
+                hidden=move || !show_list()
+            >
+                {move || {
+                    let typed = value.get();
+                    suggestions().into_iter().enumerate().map(|(i, suggestion)| {
+                        let matched = matched_prefix_len(&suggestion.text, typed.trim_start()).unwrap_or(0);
+                        let (head, tail) = suggestion.text.split_at(matched);
+                        let (head, tail) = (head.to_string(), tail.to_string());
+                        let chosen = suggestion.text.clone();
+                        view! {
+                            <ComboboxOption combobox=combobox index=i on_pick=move |_| on_pick(chosen.clone())>
+                                <span class="autocomplete-value"><mark>{head}</mark>{tail}</span>
+                                <span class="badge">{i18n.t(suggestion.source.label_key())}</span>
+                            </ComboboxOption>
+                        }
+                    }).collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
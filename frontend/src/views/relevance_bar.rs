@@ -0,0 +1,52 @@
+use leptos::*;
+use super::use_i18n;
+
+#[component]
+pub fn RelevanceBar(relevance: u8, score: f64) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="relevance" title=move || i18n.t("score.explanation")>
+            <div class="relevance-track">
+                <div class="relevance-fill" style=format!("width: {}%", relevance)></div>
+            </div>
+            <span class="relevance-label">
+                {move || {
+                    let distance = if score.is_finite() {
+                        i18n.number(score, 4)
+                    } else {
+                        i18n.t("result.not_available")
+                    };
+                    i18n.tf("relevance.label", &[("relevance", relevance.to_string()), ("distance", distance)])
+                }}
+            </span>
+        </div>
+    }
+}
+
+#[component]
+pub fn RelevanceCutoff(min_relevance: ReadSignal<u8>, set_min_relevance: WriteSignal<u8>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="form-group relevance-cutoff">
+            <label for="min-relevance" title=move || i18n.t("score.explanation")>
+                {move || i18n.tf("relevance.cutoff", &[("relevance", min_relevance.get().to_string())])}
+            </label>
+            <input
+                type="range"
+                id="min-relevance"
+                min="0"
+                max="100"
+                step="5"
+                aria-valuetext=move || format!("{}%", min_relevance.get())
+                prop:value=move || min_relevance.get()
+                on:input=move |ev| {
+                    if let Ok(val) = event_target_value(&ev).parse::<u8>() {
+                        set_min_relevance.set(val);
+                    }
+                }
+            />
+        </div>
+    }
+}
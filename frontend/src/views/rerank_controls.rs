@@ -0,0 +1,88 @@
+use leptos::*;
+use crate::models::rerank::RerankWeights;
+use super::use_i18n;
+
+#[component]
+fn WeightSlider(
+    id: &'static str,
+    label_key: &'static str,
+    value: Signal<f64>,
+    on_change: impl Fn(f64) + 'static,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="weight-slider">
+            <label for=id>{move || i18n.t(label_key)} ": " {move || i18n.number(value.get(), 1)}</label>
+            <input
+                type="range"
+                id=id
+                min="0"
+                max="1"
+                step="0.1"
+                aria-valuetext=move || i18n.number(value.get(), 1)
+                prop:value=move || value.get()
+                on:input=move |ev| {
+                    if let Ok(val) = event_target_value(&ev).parse::<f64>() {
+                        on_change(val);
+                    }
+                }
+            />
+        </div>
+    }
+}
+
+#[component]
+pub fn RerankControls(
+    enabled: ReadSignal<bool>,
+    set_enabled: WriteSignal<bool>,
+    weights: ReadSignal<RerankWeights>,
+    set_weights: WriteSignal<RerankWeights>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="rerank-controls">
+            <label class="inline-label">
+                <input
+                    type="checkbox"
+                    prop:checked=move || enabled.get()
+                    on:change=move |ev| set_enabled.set(event_target_checked(&ev))
+                />
+                " " {move || i18n.t("rerank.toggle")}
+            </label>
+
+            <Show when=move || enabled.get()>
+                <div class="weight-sliders">
+                    <WeightSlider
+                        id="weight-relevance"
+                        label_key="rerank.relevance"
+                        value=Signal::derive(move || weights.get().relevance)
+                        on_change=move |v| set_weights.update(|w| w.relevance = v)
+                    />
+                    <WeightSlider
+                        id="weight-rating"
+                        label_key="rerank.rating"
+                        value=Signal::derive(move || weights.get().rating)
+                        on_change=move |v| set_weights.update(|w| w.rating = v)
+                    />
+                    <WeightSlider
+                        id="weight-length"
+                        label_key="rerank.length"
+                        value=Signal::derive(move || weights.get().length)
+                        on_change=move |v| set_weights.update(|w| w.length = v)
+                    />
+                    <WeightSlider
+                        id="weight-recency"
+                        label_key="rerank.recency"
+                        value=Signal::derive(move || weights.get().recency)
+                        on_change=move |v| set_weights.update(|w| w.recency = v)
+                    />
+                    <button type="button" on:click=move |_| set_weights.set(RerankWeights::default())>
+                        {move || i18n.t("rerank.reset")}
+                    </button>
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,56 @@
+use leptos::*;
+use crate::models::search::SearchResult;
+use super::{use_i18n, RelevanceBar, StarRating};
+
+#[derive(Debug, Clone)]
+pub struct DisplayedResult {
+    pub position: usize,
+    pub original_rank: usize,
+    pub relevance: u8,
+    pub result: SearchResult,
+}
+
+fn or_fallback<'a>(value: &'a str, fallback: &'a str) -> &'a str {
+    if value.trim().is_empty() { fallback } else { value }
+}
+
+#[component]
+pub fn ResultCard(item: DisplayedResult) -> impl IntoView {
+    let DisplayedResult { position, original_rank, relevance, result } = item;
+    let i18n = use_i18n();
+    let title = result.review_title.clone();
+    let body = result.review_body.clone();
+    let moved = (position != original_rank).then(|| view! {
+        <span class="rank-change">
+            " " {move || i18n.tf("result.originally", &[("rank", (original_rank + 1).to_string())])}
+        </span>
+    });
+
+    view! {
+        <div class="result-item" tabindex="0">
+            <h3>
+                {position + 1} ". "
+                {move || or_fallback(&title, &i18n.t("result.untitled")).to_string()}
+                {moved}
+            </h3>
+            <p>
+                <strong>{move || i18n.t("result.review")} " "</strong>
+                {move || or_fallback(&body, &i18n.t("result.no_content")).to_string()}
+            </p>
+            <p>
+                <strong>{move || i18n.t("result.rating")} " "</strong>
+                <StarRating value=result.review_rating as f64/>
+            </p>
+            <RelevanceBar relevance=relevance score=result.score as f64/>
+            <p class="meta">
+                {move || i18n.t("result.product")} " "
+                {if result.product_id.trim().is_empty() {
+                    (move || i18n.t("result.not_available")).into_view()
+                } else {
+                    let href = format!("/products/{}", result.product_id);
+                    view! { <a href=href>{result.product_id.clone()}</a> }.into_view()
+                }}
+            </p>
+        </div>
+    }
+}
@@ -0,0 +1,55 @@
+use leptos::*;
+use wasm_bindgen::JsCast;
+use super::use_i18n;
+
+// Moves focus between the `.result-item` cards inside the list with the up and
+// down arrow keys (plus Home/End), starting from whichever card currently has focus.
+// A `VirtualList` inside handles the keys that would leave its rendered rows.
+fn move_focus(ev: &ev::KeyboardEvent) {
+    let step: fn(usize, usize) -> usize = match ev.key().as_str() {
+        "ArrowDown" => |i, len| (i + 1).min(len - 1),
+        "ArrowUp" => |i, _| i.saturating_sub(1),
+        "Home" => |_, _| 0,
+        "End" => |_, len| len - 1,
+        _ => return,
+    };
+    let Some(list) = ev.current_target().and_then(|t| t.dyn_into::<web_sys::Element>().ok()) else { return };
+    let Ok(cards) = list.query_selector_all(".result-item") else { return };
+    let len = cards.length() as usize;
+    if len == 0 {
+        return;
+    }
+
+    // Focus may be on a link inside a card rather than on the card itself.
+    let active: Option<web_sys::Node> = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element())
+        .and_then(|el| el.closest(".result-item").ok().flatten())
+        .map(Into::into);
+    let current = active.and_then(|active| (0..len).find(|&i| cards.item(i as u32).as_ref() == Some(&active)));
+    let next = current.map_or(0, |i| step(i, len));
+
+    if let Some(card) = cards.item(next as u32).and_then(|c| c.dyn_into::<web_sys::HtmlElement>().ok()) {
+        ev.prevent_default();
+        let _ = card.focus();
+    }
+}
+
+// Each top-level row goes in its own `role="listitem"` wrapper (a
+// `VirtualList` adds them itself); cards nested in a row, like a product
+// group's members, belong to that item rather than to the list.
+#[component]
+pub fn ResultList(children: Children) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div
+            class="result-list"
+            role="list"
+            aria-label=move || i18n.t("search.results_list")
+            on:keydown=move |ev| move_focus(&ev)
+        >
+            {children()}
+        </div>
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+use leptos::*;
+use super::use_i18n;
+
+const TICK: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryNotice {
+    // `Date.now()` timestamp at which the wait is over.
+    pub until_ms: f64,
+    // Message catalog key, completed by the seconds remaining.
+    pub label_key: &'static str,
+}
+
+impl RetryNotice {
+    pub fn after(delay_ms: u64, label_key: &'static str) -> Self {
+        Self {
+            until_ms: js_sys::Date::now() + delay_ms as f64,
+            label_key,
+        }
+    }
+}
+
+// Seconds left on the notice, or `None` once it has elapsed.
+pub fn use_countdown(notice: ReadSignal<Option<RetryNotice>>) -> Signal<Option<u64>> {
+    let (now, set_now) = create_signal(0.0);
+    create_effect(move |_| {
+        set_now.set(js_sys::Date::now());
+        if let Ok(handle) = set_interval_with_handle(move || set_now.set(js_sys::Date::now()), TICK) {
+            on_cleanup(move || handle.clear());
+        }
+    });
+
+    Signal::derive(move || {
+        let notice = notice.get()?;
+        let remaining = notice.until_ms - now.get();
+        (remaining > 0.0).then(|| (remaining / 1000.0).ceil() as u64)
+    })
+}
+
+#[component]
+pub fn RetryCountdown(notice: ReadSignal<Option<RetryNotice>>, remaining: Signal<Option<u64>>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        {move || remaining.get().zip(notice.get()).map(|(secs, notice)| view! {
+            <div class="retry-countdown">
+                {i18n.t(notice.label_key)} " " {i18n.tf("countdown.seconds", &[("seconds", secs.to_string())])}
+            </div>
+        })}
+    }
+}
@@ -1,6 +1,14 @@
 use leptos::*;
 use crate::controllers::api::submit_review;
+use crate::controllers::error_reporting::report_api_error;
+use crate::models::notification::NotificationLevel;
 use crate::models::review::ReviewInput;
+use super::{
+    use_backend_unreachable, use_countdown, use_current_index, use_i18n, use_notifications, BackendUnavailableNotice,
+    ProductIdInput, RetryCountdown, RetryNotice, StarRating, Toast,
+};
+
+const REVIEW_TOAST: &str = "review";
 
 #[component]
 pub fn ReviewForm() -> impl IntoView {
@@ -8,8 +16,13 @@ pub fn ReviewForm() -> impl IntoView {
     let (body, set_body) = create_signal(String::new());
     let (product_id, set_product_id) = create_signal(String::new());
     let (rating, set_rating) = create_signal(5);
-    let (message, set_message) = create_signal(None::<(bool, String)>);
+    let index = use_current_index();
+    let i18n = use_i18n();
+    let notifications = use_notifications();
     let (is_submitting, set_is_submitting) = create_signal(false);
+    let backend_unreachable = use_backend_unreachable();
+    let (retry_notice, set_retry_notice) = create_signal(None::<RetryNotice>);
+    let retry_remaining = use_countdown(retry_notice);
 
     let on_submit = move |ev: ev::SubmitEvent| {
         ev.prevent_default();
@@ -19,22 +32,34 @@ pub fn ReviewForm() -> impl IntoView {
             review_body: body.get(),
             product_id: product_id.get(),
             review_rating: rating.get(),
+            index: index.get(),
         };
 
         set_is_submitting.set(true);
-        set_message.set(None);
+        notifications.dismiss_source(REVIEW_TOAST);
 
         spawn_local(async move {
             match submit_review(review).await {
                 Ok(_) => {
-                    set_message.set(Some((true, "✓ Review submitted successfully!".to_string())));
+                    notifications.push(
+                        Toast::new(NotificationLevel::Success, i18n.t("review.submitted")).from_source(REVIEW_TOAST),
+                    );
                     set_title.set(String::new());
                     set_body.set(String::new());
                     set_product_id.set(String::new());
                     set_rating.set(5);
                 }
+                // Submissions are not idempotent, so wait out the limit instead of retrying.
+                Err(e) if e.is_retryable() => {
+                    let delay = e.retry_after_ms().unwrap_or(5_000);
+                    set_retry_notice.set(Some(RetryNotice::after(delay, "review.rate_limited")));
+                }
                 Err(e) => {
-                    set_message.set(Some((false, format!("✗ Error: {}", e))));
+                    report_api_error(&e);
+                    notifications.push(
+                        Toast::new(NotificationLevel::Error, i18n.tf("error.generic", &[("message", e.to_string())]))
+                            .from_source(REVIEW_TOAST),
+                    );
                 }
             }
             set_is_submitting.set(false);
@@ -43,10 +68,10 @@ pub fn ReviewForm() -> impl IntoView {
 
     view! {
         <div class="container">
-            <h2>"Add Review"</h2>
+            <h2>{move || i18n.t("review.heading")}</h2>
             <form on:submit=on_submit>
                 <div class="form-group">
-                    <label for="title">"Review Title:"</label>
+                    <label for="title">{move || i18n.t("review.title_label")}</label>
                     <input
                         type="text"
                         id="title"
@@ -57,7 +82,7 @@ pub fn ReviewForm() -> impl IntoView {
                 </div>
                 
                 <div class="form-group">
-                    <label for="body">"Review Body:"</label>
+                    <label for="body">{move || i18n.t("review.body_label")}</label>
                     <textarea
                         id="body"
                         prop:value=move || body.get()
@@ -67,42 +92,34 @@ pub fn ReviewForm() -> impl IntoView {
                 </div>
                 
                 <div class="form-group">
-                    <label for="product-id">"Product ID:"</label>
-                    <input
-                        type="text"
+                    <label for="product-id">{move || i18n.t("review.product_label")}</label>
+                    <ProductIdInput
                         id="product-id"
-                        prop:value=move || product_id.get()
-                        on:input=move |ev| set_product_id.set(event_target_value(&ev))
-                        required
+                        value=product_id
+                        on_input=move |val: String| set_product_id.set(val)
                     />
                 </div>
                 
                 <div class="form-group">
-                    <label for="rating">"Rating (1-5):"</label>
-                    <input
-                        type="number"
-                        id="rating"
-                        min="1"
-                        max="5"
-                        prop:value=move || rating.get()
-                        on:input=move |ev| {
-                            if let Ok(val) = event_target_value(&ev).parse::<i32>() {
-                                set_rating.set(val);
-                            }
-                        }
-                        required
+                    <label id="rating-label">{move || i18n.t("review.rating_label")}</label>
+                    <StarRating
+                        value=Signal::derive(move || rating.get() as f64)
+                        on_change=move |val: u8| set_rating.set(val as i32)
+                        labelled_by="rating-label"
+                        described_by="rating-hint"
                     />
+                    <small id="rating-hint" class="hint">{move || i18n.t("review.rating_hint")}</small>
                 </div>
                 
-                <button type="submit" disabled=move || is_submitting.get()>
-                    {move || if is_submitting.get() { "Submitting..." } else { "Submit Review" }}
+                <button
+                    type="submit"
+                    disabled=move || is_submitting.get() || backend_unreachable.get() || retry_remaining.get().is_some()
+                >
+                    {move || i18n.t(if is_submitting.get() { "review.submitting" } else { "review.submit" })}
                 </button>
+                <BackendUnavailableNotice/>
+                <RetryCountdown notice=retry_notice remaining=retry_remaining/>
             </form>
-            
-            {move || message.get().map(|(is_success, msg)| {
-                let class = if is_success { "success" } else { "error" };
-                view! { <div class=class>{msg}</div> }
-            })}
         </div>
     }
 }
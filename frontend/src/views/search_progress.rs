@@ -0,0 +1,68 @@
+use std::time::Duration;
+use leptos::*;
+use super::use_i18n;
+
+const TICK: Duration = Duration::from_millis(250);
+// Most searches finish sooner; showing the timer for those would only flash.
+const SLOW_AFTER_MS: f64 = 1_000.0;
+const MAX_SKELETONS: usize = 5;
+
+// Milliseconds since `active` last turned true, or `None` while it's false.
+pub fn use_elapsed(active: Signal<bool>) -> Signal<Option<f64>> {
+    let (elapsed, set_elapsed) = create_signal(None::<f64>);
+    create_effect(move |_| {
+        if !active.get() {
+            set_elapsed.set(None);
+            return;
+        }
+        let started = js_sys::Date::now();
+        set_elapsed.set(Some(0.0));
+        if let Ok(handle) = set_interval_with_handle(move || set_elapsed.set(Some(js_sys::Date::now() - started)), TICK) {
+            on_cleanup(move || handle.clear());
+        }
+    });
+    elapsed.into()
+}
+
+// Elapsed time and a cancel button, shown once a search has been running for
+// a noticeable while.
+#[component]
+pub fn SearchProgress(#[prop(into)] searching: Signal<bool>, #[prop(into)] on_cancel: Callback<()>) -> impl IntoView {
+    let i18n = use_i18n();
+    let elapsed = use_elapsed(searching);
+    let is_slow = move || elapsed.get().is_some_and(|ms| ms >= SLOW_AFTER_MS);
+    let seconds = move || elapsed.get().map_or(0, |ms| (ms / 1000.0).floor() as u64);
+
+    // Only the "still searching" notice is announced; a screen reader reading
+    // out every tick of the timer would drown everything else.
+    view! {
+        <div class="search-progress">
+            <span role="status">{move || is_slow().then(|| i18n.t("search.still_searching"))}</span>
+            <Show when=is_slow>
+                <span class="meta" aria-hidden="true">
+                    {move || i18n.tf("countdown.seconds", &[("seconds", seconds().to_string())])}
+                </span>
+                <button type="button" class="link-button" on:click=move |_| on_cancel.call(())>
+                    {move || i18n.t("search.cancel")}
+                </button>
+            </Show>
+        </div>
+    }
+}
+
+// Placeholder cards shown while the first results are on their way.
+#[component]
+pub fn ResultSkeletons(count: usize) -> impl IntoView {
+    view! {
+        <div class="result-skeletons" aria-hidden="true">
+            {(0..count.clamp(1, MAX_SKELETONS)).map(|_| view! {
+                <div class="result-item skeleton">
+                    <div class="skeleton-line skeleton-title"></div>
+                    <div class="skeleton-line"></div>
+                    <div class="skeleton-line"></div>
+                    <div class="skeleton-line skeleton-short"></div>
+                </div>
+            }).collect_view()}
+        </div>
+    }
+}
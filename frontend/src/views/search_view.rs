@@ -1,67 +1,342 @@
 use leptos::*;
-use crate::controllers::api::search_reviews;
-use crate::models::search::SearchResponse;
+use leptos_router::use_query_map;
+use crate::controllers::api::search_reviews_cached;
+use crate::controllers::error_reporting::{report_api_error, set_last_query};
+use crate::controllers::export::{export_results, ExportFormat};
+use crate::controllers::telemetry::{perf_now_ms, record_render_time};
+use crate::controllers::transport::fetch_search;
+use crate::models::facets::{FacetFilter, ResultFacets};
+use crate::models::grouping::{group_by_product, split_by_group, ProductGroup};
+use crate::models::notification::NotificationLevel;
+use crate::models::rerank::{rerank, RerankWeights};
+use crate::models::score::relevance;
+use crate::models::search::{SearchResponse, SearchResult};
+use super::{
+    use_backend_unreachable, use_countdown, BackendUnavailableNotice, DisplayedResult, FacetChips,
+    use_current_index, use_i18n, use_notifications, use_search_history, ProductGroupCard, QueryInput,
+    RelevanceCutoff, RerankControls, ResultCard, ResultList, ResultSkeletons, RetryCountdown, RetryNotice,
+    SearchProgress, Toast, VirtualList, VIRTUALIZE_THRESHOLD,
+};
+
+const DEFAULT_K: usize = 3;
+const SEARCH_TOAST: &str = "search";
+const ESTIMATED_CARD_HEIGHT: f64 = 190.0;
+// A collapsed group: header, best card and the "show more" button.
+const ESTIMATED_GROUP_HEIGHT: f64 = 250.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DisplayMode {
+    List,
+    GroupedByProduct,
+}
+
+// Why a search runs: refreshes skip the cache, and only a submitted search
+// moves focus away from whatever control the user is on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SearchTrigger {
+    Submit,
+    Retry,
+    Refresh,
+    IndexChange,
+}
+
+impl SearchTrigger {
+    fn force_refresh(self) -> bool {
+        self == SearchTrigger::Refresh
+    }
+
+    fn moves_focus(self) -> bool {
+        self == SearchTrigger::Submit
+    }
+}
 
 #[component]
 pub fn SearchView() -> impl IntoView {
-    let (query, set_query) = create_signal(String::new());
-    let (k, set_k) = create_signal(3);
+    let url_query = use_query_map();
+    let index = use_current_index();
+    let i18n = use_i18n();
+    let url_search = move || {
+        url_query.with(|params| {
+            let q = params.get("q").cloned().unwrap_or_default();
+            let k = params.get("k").and_then(|k| k.parse().ok()).unwrap_or(DEFAULT_K);
+            (q, k, index.get())
+        })
+    };
+    // `?q=` links are searched during server rendering so the results are in
+    // the first paint (and crawlable); the browser hydrates the same data.
+    let initial_results = create_resource(url_search, |(q, k, index)| async move {
+        if q.trim().is_empty() {
+            return None;
+        }
+        fetch_search(q, k, index).await.ok()
+    });
+    let (initial_query, initial_k, initial_index) = untrack(url_search);
+
+    let (query, set_query) = create_signal(initial_query.clone());
+    let (k, set_k) = create_signal(initial_k);
     let (results, set_results) = create_signal(Option::<SearchResponse>::None);
-    let (message, set_message) = create_signal(None::<(bool, String)>);
+    let (has_searched, set_has_searched) = create_signal(false);
+    let current_results = Signal::derive(move || {
+        if has_searched.get() {
+            results.get()
+        } else {
+            initial_results.get().flatten()
+        }
+    });
+    let notifications = use_notifications();
+    let history = use_search_history();
+    // `run_search` can't refer to itself, so its Retry action goes through here.
+    let search_again = store_value(None::<Callback<(String, usize, String)>>);
+    let results_heading = create_node_ref::<html::H2>();
     let (is_searching, set_is_searching) = create_signal(false);
+    // Bumped by every search and by cancelling, so a response that arrives
+    // for anything but the latest search is dropped.
+    let search_generation = store_value(0u64);
+    // Aborts the request of the search in flight, if any.
+    let search_abort = store_value(None::<web_sys::AbortController>);
+    let backend_unreachable = use_backend_unreachable();
+    let (retry_notice, set_retry_notice) = create_signal(None::<RetryNotice>);
+    let retry_remaining = use_countdown(retry_notice);
+    let (last_search, set_last_search) = create_signal((initial_query, initial_k, initial_index));
+    let (from_cache, set_from_cache) = create_signal(false);
+    let (min_relevance, set_min_relevance) = create_signal(0u8);
+    let (rerank_enabled, set_rerank_enabled) = create_signal(false);
+    let (weights, set_weights) = create_signal(RerankWeights::default());
+    let (display_mode, set_display_mode) = create_signal(DisplayMode::List);
+    let (facet_filter, set_facet_filter) = create_signal(FacetFilter::default());
+    let facets = Signal::derive(move || {
+        current_results
+            .get()
+            .map(|res| ResultFacets::from_results(&res.results))
+            .unwrap_or_default()
+    });
 
-    let on_submit = move |ev: ev::SubmitEvent| {
-        ev.prevent_default();
-        
-        if query.get().trim().is_empty() {
-            set_message.set(Some((false, "Please enter a search query".to_string())));
-            return;
+    let run_search = move |q: String, k_val: usize, index: String, trigger: SearchTrigger| {
+        let generation = search_generation.get_value() + 1;
+        search_generation.set_value(generation);
+        let controller = web_sys::AbortController::new().ok();
+        let signal = controller.as_ref().map(|c| c.signal());
+        if let Some(previous) = search_abort.try_update_value(|c| std::mem::replace(c, controller)).flatten() {
+            previous.abort();
+        }
+        // Previous results stay on screen, dimmed, until the new ones arrive;
+        // for the first search in the browser those are the server-rendered ones.
+        if !has_searched.get_untracked() {
+            set_results.set(untrack(move || initial_results.get().flatten()));
         }
-
         set_is_searching.set(true);
-        set_message.set(None);
-        set_results.set(None);
-
-        let q = query.get();
-        let k_val = k.get();
+        set_has_searched.set(true);
+        notifications.dismiss_source(SEARCH_TOAST);
+        set_last_query(&q);
 
         spawn_local(async move {
-            match search_reviews(q, k_val).await {
-                Ok(response) => {
-                    let count = response.results.len();
-                    set_results.set(Some(response));
-                    set_message.set(Some((true, format!("✓ Found {} results", count))));
+            let is_current = move || search_generation.try_get_value() == Some(generation);
+            let on_wait = move |delay| {
+                if is_current() {
+                    set_retry_notice.set(Some(RetryNotice::after(delay, "search.busy_retry")));
+                }
+            };
+            let result = search_reviews_cached(
+                q.clone(),
+                k_val,
+                index.clone(),
+                trigger.force_refresh(),
+                signal.as_ref(),
+                on_wait,
+            )
+            .await;
+            // Cancelled, or overtaken by a newer search that now owns the UI.
+            if !is_current() {
+                return;
+            }
+            set_retry_notice.set(None);
+            match result {
+                Ok(cached) => {
+                    let count = cached.value.results.len();
+                    history.record(&q);
+                    set_facet_filter.set(FacetFilter::default());
+                    set_last_search.set((q, k_val, index));
+                    set_from_cache.set(cached.from_cache);
+                    let render_start = perf_now_ms();
+                    set_results.set(Some(cached.value));
+                    // Move keyboard and screen reader users straight to what they asked for.
+                    if trigger.moves_focus() {
+                        request_animation_frame(move || {
+                            if let Some(heading) = results_heading.get_untracked() {
+                                let _ = heading.focus();
+                            }
+                        });
+                    }
+                    // Cache hits never reached the network, so there is no sample to complete.
+                    if !cached.from_cache {
+                        request_animation_frame(move || record_render_time(perf_now_ms() - render_start));
+                    }
+                    if count == 0 {
+                        notifications.push(
+                            Toast::new(NotificationLevel::Info, i18n.t("search.no_results")).from_source(SEARCH_TOAST),
+                        );
+                    } else {
+                        notifications.push(
+                            Toast::new(NotificationLevel::Success, i18n.plural("search.found", count, &[]))
+                                .from_source(SEARCH_TOAST),
+                        );
+                    }
+                }
+                Err(e) if e.is_retryable() => {
+                    let delay = e.retry_after_ms().unwrap_or(5_000);
+                    set_retry_notice.set(Some(RetryNotice::after(delay, "search.rate_limited")));
                 }
                 Err(e) => {
-                    set_message.set(Some((false, format!("✗ Error: {}", e))));
+                    report_api_error(&e);
+                    let toast = Toast::new(
+                        NotificationLevel::Error,
+                        i18n.tf("error.generic", &[("message", e.to_string())]),
+                    )
+                    .from_source(SEARCH_TOAST);
+                    let toast = match search_again.get_value() {
+                        Some(again) => toast.with_action("toast.retry", move || {
+                            again.call((q.clone(), k_val, index.clone()))
+                        }),
+                        None => toast,
+                    };
+                    notifications.push(toast);
                 }
             }
             set_is_searching.set(false);
         });
     };
+    search_again.set_value(Some(Callback::new(move |(q, k_val, index)| {
+        run_search(q, k_val, index, SearchTrigger::Retry)
+    })));
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        
+        if query.get().trim().is_empty() {
+            notifications.push(
+                Toast::new(NotificationLevel::Warning, i18n.t("search.empty_query")).from_source(SEARCH_TOAST),
+            );
+            return;
+        }
+
+        run_search(query.get(), k.get(), index.get_untracked(), SearchTrigger::Submit);
+    };
+
+    let on_cancel = move |_| {
+        if let Some(controller) = search_abort.try_update_value(Option::take).flatten() {
+            controller.abort();
+        }
+        search_generation.update_value(|g| *g += 1);
+        set_retry_notice.set(None);
+        set_is_searching.set(false);
+    };
+
+    let on_refresh = move |_| {
+        let (q, k_val, index) = last_search.get_untracked();
+        run_search(q, k_val, index, SearchTrigger::Refresh);
+    };
+
+    // What the results list shows, after the relevance cutoff, re-ranking and
+    // facet filters, plus how many results the cutoff hid. Exports use it too,
+    // so a file always matches the screen.
+    let displayed = Signal::derive(move || {
+        current_results.get().map(|res| {
+            let cutoff = min_relevance.get();
+            let total = res.results.len();
+            let ordered: Vec<_> = if rerank_enabled.get() {
+                rerank(&res.results, weights.get())
+                    .into_iter()
+                    .map(|ranked| (ranked.original_rank, ranked.result))
+                    .collect()
+            } else {
+                res.results.into_iter().enumerate().collect()
+            };
+            let visible: Vec<DisplayedResult> = ordered
+                .into_iter()
+                .enumerate()
+                .map(|(position, (original_rank, result))| DisplayedResult {
+                    position,
+                    original_rank,
+                    relevance: relevance(result.score as f64),
+                    result,
+                })
+                .filter(|item| item.relevance >= cutoff)
+                .collect();
+            let hidden = total - visible.len();
+            let filter = facet_filter.get();
+            let visible: Vec<DisplayedResult> = visible
+                .into_iter()
+                .filter(|item| filter.matches(&item.result))
+                .collect();
+            (visible, hidden)
+        })
+    });
+
+    // Results from one index are meaningless in another, so switching the
+    // index repeats the last search against the new one.
+    create_effect(move |previous: Option<String>| {
+        let current = index.get();
+        if previous.is_some_and(|p| p != current) && has_searched.get_untracked() {
+            let (q, k_val, _) = last_search.get_untracked();
+            run_search(q, k_val, current.clone(), SearchTrigger::IndexChange);
+        }
+        current
+    });
+
+    let is_saved = move || last_search.with(|(q, _, _)| history.is_saved(q));
+    // Un-saving is the only way to lose a saved search, so it can be taken back.
+    let on_toggle_saved = move |_| {
+        let q = last_search.with_untracked(|(q, _, _)| q.clone());
+        let was_saved = untrack(|| history.is_saved(&q));
+        history.toggle_saved(&q);
+        if was_saved {
+            let message = i18n.tf("search.unsaved", &[("query", q.trim().to_string())]);
+            notifications.push(
+                Toast::new(NotificationLevel::Info, message)
+                    .from_source("saved-search")
+                    .with_action("toast.undo", move || {
+                        if !untrack(|| history.is_saved(&q)) {
+                            history.toggle_saved(&q);
+                        }
+                    }),
+            );
+        }
+    };
+
+    let on_export = move |format: ExportFormat| {
+        let Some((visible, _)) = displayed.get_untracked() else { return };
+        let ranked: Vec<(usize, &SearchResult)> =
+            visible.iter().map(|item| (item.position + 1, &item.result)).collect();
+        let (q, k_val, _) = last_search.get_untracked();
+        if let Err(e) = export_results(format, &q, k_val, &ranked) {
+            notifications.error(i18n.tf("search.export_failed", &[("message", e.to_string())]));
+        }
+    };
 
     view! {
         <div class="container">
-            <h2>"Search Reviews"</h2>
+            <h2>{move || i18n.t("search.heading")}</h2>
             <form on:submit=on_submit>
                 <div class="form-group">
-                    <label for="query">"Search Query:"</label>
-                    <input
-                        type="text"
+                    <label for="query">{move || i18n.t("search.query_label")}</label>
+                    <QueryInput
                         id="query"
-                        placeholder="e.g., SPFresh"
-                        prop:value=move || query.get()
-                        on:input=move |ev| set_query.set(event_target_value(&ev))
-                        required
+                        value=query
+                        on_input=move |val: String| set_query.set(val)
+                        history=history
+                        placeholder=Signal::derive(move || i18n.t("search.query_placeholder"))
                     />
                 </div>
                 
                 <div class="form-group">
-                    <label for="k">"Number of Results (k):"</label>
+                    <label for="k">{move || i18n.t("search.k_label")}</label>
                     <input
                         type="number"
                         id="k"
                         min="1"
+                        step="1"
+                        inputmode="numeric"
+                        aria-describedby="k-hint"
                         prop:value=move || k.get()
                         on:input=move |ev| {
                             if let Ok(val) = event_target_value(&ev).parse::<usize>() {
@@ -70,45 +345,131 @@ pub fn SearchView() -> impl IntoView {
                         }
                         required
                     />
+                    <small id="k-hint" class="hint">{move || i18n.t("search.k_hint")}</small>
                 </div>
                 
-                <button type="submit" disabled=move || is_searching.get()>
-                    {move || if is_searching.get() { "Searching..." } else { "Search" }}
+                <button
+                    type="submit"
+                    disabled=move || is_searching.get() || backend_unreachable.get() || retry_remaining.get().is_some()
+                >
+                    {move || i18n.t(if is_searching.get() { "search.searching" } else { "search.submit" })}
                 </button>
+                <BackendUnavailableNotice/>
+                <RetryCountdown notice=retry_notice remaining=retry_remaining/>
+                <SearchProgress searching=is_searching on_cancel=on_cancel/>
             </form>
-            
-            {move || message.get().map(|(is_success, msg)| {
-                let class = if is_success { "success" } else { "error" };
-                view! { <div class=class>{msg}</div> }
-            })}
         </div>
 
-        <Show when=move || results.get().is_some()>
-            <div class="container results">
-                <h2>"Search Results"</h2>
-                {move || results.get().map(|res| view! {
-                    <div>
-                        <For
-                            each=move || res.results.clone()
-                            key=|r| format!("{}-{}", r.product_id, r.score)
-                            children=move |result| {
-                                let stars = "⭐".repeat(result.review_rating.max(0).min(5) as usize);
-                                view! {
-                                    <div class="result-item">
-                                        <h3>{result.review_title.clone()}</h3>
-                                        <p><strong>"Review: "</strong> {result.review_body.clone()}</p>
-                                        <p><strong>"Rating: "</strong> {stars} " (" {result.review_rating} "/5)"</p>
-                                        <p class="meta">
-                                            "Product ID: " {result.product_id.clone()} " | "
-                                            "Score: " {format!("{:.4}", result.score)}
-                                        </p>
-                                    </div>
-                                }
-                            }
-                        />
-                    </div>
-                })}
+        <Show when=move || is_searching.get() && current_results.get().is_none()>
+            <div class="container results" aria-busy="true">
+                <ResultSkeletons count=k.get_untracked()/>
             </div>
         </Show>
+        <Suspense fallback=|| ()>
+            <Show when=move || current_results.get().is_some()>
+                <div
+                    class="container results"
+                    class:stale=move || is_searching.get()
+                    aria-busy=move || is_searching.get().to_string()
+                >
+                    <div class="results-header">
+                        <h2 tabindex="-1" node_ref=results_heading>
+                            {move || i18n.t("search.results_heading")}
+                            <Show when=move || from_cache.get()>
+                                <span class="badge" title=move || i18n.t("search.cached_title")>
+                                    {move || i18n.t("search.cached")}
+                                </span>
+                            </Show>
+                        </h2>
+                        <div class="export-actions">
+                            <button
+                                type="button"
+                                aria-pressed=move || is_saved().to_string()
+                                on:click=on_toggle_saved
+                            >
+                                {move || i18n.t(if is_saved() { "search.saved" } else { "search.save" })}
+                            </button>
+                            <button type="button" on:click=on_refresh disabled=move || is_searching.get()>
+                                {move || i18n.t("search.refresh")}
+                            </button>
+                            <button type="button" on:click=move |_| on_export(ExportFormat::Csv)>
+                                {move || i18n.t("search.export_csv")}
+                            </button>
+                            <button type="button" on:click=move |_| on_export(ExportFormat::Json)>
+                                {move || i18n.t("search.export_json")}
+                            </button>
+                        </div>
+                    </div>
+                    <RelevanceCutoff min_relevance=min_relevance set_min_relevance=set_min_relevance/>
+                    <FacetChips facets=facets filter=facet_filter set_filter=set_facet_filter/>
+                    <div class="display-mode">
+                        <label class="inline-label">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || display_mode.get() == DisplayMode::GroupedByProduct
+                                on:change=move |ev| set_display_mode.set(if event_target_checked(&ev) {
+                                    DisplayMode::GroupedByProduct
+                                } else {
+                                    DisplayMode::List
+                                })
+                            />
+                            " " {move || i18n.t("search.group_by_product")}
+                        </label>
+                    </div>
+                    <RerankControls
+                        enabled=rerank_enabled
+                        set_enabled=set_rerank_enabled
+                        weights=weights
+                        set_weights=set_weights
+                    />
+                    {move || displayed.get().map(|(visible, hidden)| {
+                        let list = match display_mode.get() {
+                            DisplayMode::List if visible.len() > VIRTUALIZE_THRESHOLD => view! {
+                                <VirtualList
+                                    items=visible
+                                    estimated_row_height=ESTIMATED_CARD_HEIGHT
+                                    row=|item: DisplayedResult| view! { <ResultCard item=item/> }
+                                />
+                            }.into_view(),
+                            DisplayMode::List => view! {
+                                <For
+                                    each=move || visible.clone()
+                                    key=|item| item.original_rank
+                                    children=move |item| view! { <div role="listitem"><ResultCard item=item/></div> }
+                                />
+                            }.into_view(),
+                            DisplayMode::GroupedByProduct => {
+                                let groups = group_by_product(visible.iter().map(|item| &item.result));
+                                let members = split_by_group(&groups, visible);
+                                let grouped: Vec<(ProductGroup, Vec<DisplayedResult>)> =
+                                    groups.into_iter().zip(members).collect();
+                                let card = |(group, members): (ProductGroup, Vec<DisplayedResult>)| view! {
+                                    <ProductGroupCard group=group members=members/>
+                                };
+                                if grouped.len() > VIRTUALIZE_THRESHOLD {
+                                    view! {
+                                        <VirtualList items=grouped estimated_row_height=ESTIMATED_GROUP_HEIGHT row=card/>
+                                    }.into_view()
+                                } else {
+                                    grouped
+                                        .into_iter()
+                                        .map(|group| view! { <div role="listitem">{card(group)}</div> })
+                                        .collect_view()
+                                }
+                            }
+                        };
+
+                        view! {
+                            <div>
+                                <ResultList>{list}</ResultList>
+                                <Show when=move || { hidden > 0 }>
+                                    <p class="meta">{move || i18n.plural("search.hidden", hidden, &[])}</p>
+                                </Show>
+                            </div>
+                        }
+                    })}
+                </div>
+            </Show>
+        </Suspense>
     }
 }
\ No newline at end of file
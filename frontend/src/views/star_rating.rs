@@ -0,0 +1,89 @@
+use leptos::*;
+use wasm_bindgen::JsCast;
+use crate::models::rating::{star_fills, MAX_RATING};
+use super::use_i18n;
+
+// Read-only unless `on_change` is given. Editable stars behave like a radio
+// group: click or use the arrow keys to pick, hover to preview.
+#[component]
+pub fn StarRating(
+    #[prop(into)] value: MaybeSignal<f64>,
+    #[prop(optional, into)] on_change: Option<Callback<u8>>,
+    // Id of the visible label, for editable ratings inside a form.
+    #[prop(optional)] labelled_by: Option<&'static str>,
+    #[prop(optional)] described_by: Option<&'static str>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let Some(on_change) = on_change else {
+        return view! {
+            <span
+                class="star-rating"
+                role="img"
+                aria-label=move || i18n.tf("rating.summary", &[("rating", i18n.number(value.get(), 1))])
+            >
+                {move || star_fills(value.get()).into_iter().map(|fill| view! {
+                    <span class=format!("star {}", fill.css_class()) aria-hidden="true">"★"</span>
+                }).collect_view()}
+            </span>
+        }.into_view();
+    };
+
+    let hovered = create_rw_signal(None::<u8>);
+    let group = create_node_ref::<html::Div>();
+    let selected = move || value.get().round().clamp(0.0, MAX_RATING as f64) as u8;
+    // Only one star is in the tab order; with nothing picked yet that's the first.
+    let focus_target = move || selected().max(1);
+    let shown = move || hovered.get().map_or_else(|| value.get(), f64::from);
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let current = focus_target();
+        let next = match ev.key().as_str() {
+            "ArrowRight" | "ArrowUp" => (current + 1).min(MAX_RATING),
+            "ArrowLeft" | "ArrowDown" => (current - 1).max(1),
+            "Home" => 1,
+            "End" => MAX_RATING,
+            _ => return,
+        };
+        ev.prevent_default();
+        on_change.call(next);
+        let star = group
+            .get_untracked()
+            .and_then(|g| g.query_selector(&format!("[data-rating='{}']", next)).ok().flatten())
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+        if let Some(star) = star {
+            let _ = star.focus();
+        }
+    };
+
+    view! {
+        <div
+            class="star-rating editable"
+            role="radiogroup"
+            node_ref=group
+            aria-labelledby=labelled_by
+            aria-describedby=described_by
+            on:keydown=on_keydown
+            on:mouseleave=move |_| hovered.set(None)
+        >
+            {(1..=MAX_RATING).map(|rating| {
+                let fill = move || star_fills(shown())[rating as usize - 1];
+                view! {
+                    <button
+                        type="button"
+                        class=move || format!("star {}", fill().css_class())
+                        role="radio"
+                        data-rating=rating
+                        aria-checked=move || (selected() == rating).to_string()
+                        aria-label=move || i18n.plural("rating.stars", rating as usize, &[])
+                        tabindex=move || if focus_target() == rating { 0 } else { -1 }
+                        on:click=move |_| on_change.call(rating)
+                        on:mouseenter=move |_| hovered.set(Some(rating))
+                    >
+                        "★"
+                    </button>
+                }
+            }).collect_view()}
+        </div>
+    }.into_view()
+}
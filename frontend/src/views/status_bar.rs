@@ -0,0 +1,109 @@
+use std::time::Duration;
+use leptos::*;
+use crate::controllers::api::check_health;
+use crate::models::health::{BackendStatus, HealthState};
+use crate::models::i18n::Locale;
+use super::{use_i18n, I18n};
+
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+#[derive(Clone, Copy)]
+pub struct BackendHealth(pub ReadSignal<HealthState>);
+
+fn now_label(locale: Locale) -> String {
+    String::from(js_sys::Date::new_0().to_locale_time_string(locale.code()))
+}
+
+fn refresh_health(set_health: WriteSignal<HealthState>, i18n: I18n) {
+    spawn_local(async move {
+        let state = match check_health().await {
+            Ok(response) => HealthState {
+                status: if response.is_healthy() {
+                    BackendStatus::Connected
+                } else {
+                    BackendStatus::Degraded
+                },
+                detail: (!response.is_healthy()).then(|| response.status.clone()),
+                version: response.version,
+                indexed_documents: response.indexed_documents,
+                last_checked: Some(now_label(i18n.locale.get_untracked())),
+            },
+            Err(e) => HealthState {
+                status: BackendStatus::Down,
+                detail: Some(e),
+                last_checked: Some(now_label(i18n.locale.get_untracked())),
+                ..HealthState::default()
+            },
+        };
+        set_health.set(state);
+    });
+}
+
+pub fn provide_backend_health() -> ReadSignal<HealthState> {
+    let (health, set_health) = create_signal(HealthState::default());
+    let i18n = use_i18n();
+
+    // Effects only run in the browser, which keeps polling out of server rendering.
+    create_effect(move |_| {
+        refresh_health(set_health, i18n);
+        if let Ok(handle) = set_interval_with_handle(move || refresh_health(set_health, i18n), HEALTH_POLL_INTERVAL) {
+            on_cleanup(move || handle.clear());
+        }
+    });
+
+    provide_context(BackendHealth(health));
+    health
+}
+
+// Components rendered outside a provider (e.g. in isolation) assume the
+// backend is reachable rather than locking the user out.
+pub fn use_backend_unreachable() -> Signal<bool> {
+    let health = use_context::<BackendHealth>();
+    Signal::derive(move || health.is_some_and(|h| h.0.get().is_unreachable()))
+}
+
+// With no `backend_url` the backend is reached through the frontend
+// server's proxy, which is what gets shown instead.
+#[component]
+pub fn StatusBar(backend_url: Option<String>) -> impl IntoView {
+    let health = use_context::<BackendHealth>()
+        .map(|h| h.0)
+        .unwrap_or_else(provide_backend_health);
+    let i18n = use_i18n();
+
+    view! {
+        <div class=move || format!("info status-bar {}", health.get().status.css_class())>
+            <span class="status-dot"></span>
+            <strong>{move || i18n.t(health.get().status.label_key())}</strong>
+            " | "
+            <strong>{move || i18n.t("status.backend")} " "</strong>
+            {move || backend_url.clone().unwrap_or_else(|| i18n.t("app.proxied"))}
+            {move || health.get().version.map(|v| view! {
+                " | " <strong>{i18n.t("status.version")} " "</strong> {v}
+            })}
+            {move || health.get().indexed_documents.map(|n| view! {
+                " | " <strong>{i18n.t("status.indexed")} " "</strong> {i18n.number(n as f64, 0)}
+            })}
+            {move || health.get().last_checked.map(|t| view! {
+                " | " <span class="meta">{i18n.tf("status.last_checked", &[("time", t)])}</span>
+            })}
+            {move || health.get().detail.map(|d| view! {
+                <div class="status-detail">{d}</div>
+            })}
+        </div>
+    }
+}
+
+#[component]
+pub fn BackendUnavailableNotice() -> impl IntoView {
+    let unreachable = use_backend_unreachable();
+    let i18n = use_i18n();
+
+    view! {
+        <div role="status" aria-live="polite">
+            <Show when=move || unreachable.get()>
+                <p class="status-notice">{move || i18n.t("status.unreachable_notice")}</p>
+            </Show>
+        </div>
+    }
+}
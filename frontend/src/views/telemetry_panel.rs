@@ -0,0 +1,96 @@
+use std::time::Duration;
+use leptos::*;
+use crate::controllers::telemetry::{flush_telemetry, percentile, recent_timings, SearchTiming};
+use crate::config::Config;
+use super::{use_i18n, I18n};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+const FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+const SPARKLINE_POINTS: usize = 40;
+const SPARKLINE_WIDTH: f64 = 200.0;
+const SPARKLINE_HEIGHT: f64 = 40.0;
+
+fn format_ms(i18n: I18n, value: Option<f64>) -> String {
+    value.map_or_else(|| "–".to_string(), |ms| i18n.tf("telemetry.ms", &[("value", i18n.number(ms, 0))]))
+}
+
+fn sparkline_points(totals: &[f64]) -> String {
+    let max = totals.iter().copied().fold(0.0_f64, f64::max).max(1.0);
+    let step = if totals.len() > 1 {
+        SPARKLINE_WIDTH / (totals.len() - 1) as f64
+    } else {
+        0.0
+    };
+    totals
+        .iter()
+        .enumerate()
+        .map(|(i, ms)| format!("{:.1},{:.1}", i as f64 * step, SPARKLINE_HEIGHT - ms / max * SPARKLINE_HEIGHT))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[component]
+pub fn TelemetryPanel() -> impl IntoView {
+    let (timings, set_timings) = create_signal(Vec::<SearchTiming>::new());
+    let i18n = use_i18n();
+
+    // Effects only run in the browser, which keeps the timers out of server rendering.
+    create_effect(move |_| {
+        if let Ok(handle) = set_interval_with_handle(move || set_timings.set(recent_timings()), REFRESH_INTERVAL) {
+            on_cleanup(move || handle.clear());
+        }
+        if Config::get_telemetry_endpoint().is_some() {
+            let flush = || spawn_local(async {
+                let _ = flush_telemetry().await;
+            });
+            if let Ok(handle) = set_interval_with_handle(flush, FLUSH_INTERVAL) {
+                on_cleanup(move || handle.clear());
+            }
+        }
+    });
+
+    let totals = move || timings.get().iter().map(SearchTiming::total_ms).collect::<Vec<_>>();
+    let stat = move |p: f64| format_ms(i18n, percentile(&totals(), p));
+    let average = move |field: fn(&SearchTiming) -> Option<f64>| {
+        let values: Vec<f64> = timings.get().iter().filter_map(field).collect();
+        (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+    };
+
+    view! {
+        <details class="container telemetry-panel">
+            <summary>{move || i18n.t("telemetry.heading")}</summary>
+            <Show
+                when=move || !timings.get().is_empty()
+                fallback=move || view! { <p class="meta">{move || i18n.t("telemetry.empty")}</p> }
+            >
+                <div class="telemetry-stats">
+                    <span><strong>{move || i18n.t("telemetry.samples")} " "</strong> {move || timings.get().len()}</span>
+                    <span><strong>"p50: "</strong> {move || stat(50.0)}</span>
+                    <span><strong>"p95: "</strong> {move || stat(95.0)}</span>
+                    <span><strong>"p99: "</strong> {move || stat(99.0)}</span>
+                </div>
+                <div class="telemetry-stats">
+                    <span>{move || i18n.tf("telemetry.avg_network", &[("value", format_ms(i18n, average(|t| Some(t.network_ms))))])}</span>
+                    <span>{move || i18n.tf("telemetry.avg_decode", &[("value", format_ms(i18n, average(|t| Some(t.decode_ms))))])}</span>
+                    <span>{move || i18n.tf("telemetry.avg_render", &[("value", format_ms(i18n, average(|t| t.render_ms)))])}</span>
+                </div>
+                <svg
+                    class="sparkline"
+                    width=SPARKLINE_WIDTH
+                    height=SPARKLINE_HEIGHT
+                    viewBox=format!("0 0 {} {}", SPARKLINE_WIDTH, SPARKLINE_HEIGHT)
+                >
+                    <polyline
+                        fill="none"
+                        stroke-width="1.5"
+                        points=move || {
+                            let totals = totals();
+                            let start = totals.len().saturating_sub(SPARKLINE_POINTS);
+                            sparkline_points(&totals[start..])
+                        }
+                    />
+                </svg>
+            </Show>
+        </details>
+    }
+}
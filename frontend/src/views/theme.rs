@@ -0,0 +1,74 @@
+use leptos::*;
+use leptos_meta::{Html, Style};
+use crate::controllers::storage::local_storage;
+use crate::models::theme::Theme;
+use super::use_i18n;
+
+const THEME_KEY: &str = "spfresh.theme";
+
+// The whole app renders this one stylesheet, so every component shares the
+// same theme variables.
+pub const STYLESHEET: &str = include_str!("../../style/main.css");
+
+#[derive(Clone, Copy)]
+pub struct ThemeContext {
+    pub theme: RwSignal<Theme>,
+}
+
+pub fn provide_theme() -> ThemeContext {
+    let ctx = ThemeContext {
+        theme: create_rw_signal(Theme::System),
+    };
+    // Server renders use the system theme, which CSS resolves on its own, so
+    // only an explicit choice needs to be applied once the browser takes over.
+    create_effect(move |_| {
+        let stored = local_storage()
+            .and_then(|s| s.get_item(THEME_KEY).ok().flatten())
+            .and_then(|code| Theme::from_code(&code));
+        ctx.theme.set(stored.unwrap_or_default());
+    });
+    provide_context(ctx);
+    ctx
+}
+
+#[component]
+pub fn AppStyles() -> impl IntoView {
+    let ctx = use_context::<ThemeContext>().unwrap_or_else(provide_theme);
+
+    view! {
+        <Html class=move || ctx.theme.get().root_class()/>
+        <Style>{STYLESHEET}</Style>
+    }
+}
+
+#[component]
+pub fn ThemeToggle() -> impl IntoView {
+    let ctx = use_context::<ThemeContext>().unwrap_or_else(provide_theme);
+    let i18n = use_i18n();
+
+    let on_change = move |ev| {
+        let Some(theme) = Theme::from_code(&event_target_value(&ev)) else { return };
+        if let Some(storage) = local_storage() {
+            let _ = match theme {
+                Theme::System => storage.remove_item(THEME_KEY),
+                _ => storage.set_item(THEME_KEY, theme.code()),
+            };
+        }
+        ctx.theme.set(theme);
+    };
+
+    view! {
+        <select
+            class="theme-toggle"
+            aria-label=move || i18n.t("theme.label")
+            on:change=on_change
+            prop:value=move || ctx.theme.get().code()
+        >
+            {Theme::ALL.into_iter().map(|theme| view! {
+                <option value=theme.code() selected=move || ctx.theme.get() == theme>
+                    {move || i18n.t(theme.label_key())}
+                </option>
+            }).collect_view()}
+        </select>
+    }
+}
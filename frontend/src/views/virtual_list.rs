@@ -0,0 +1,174 @@
+use leptos::*;
+use wasm_bindgen::JsCast;
+
+// Below this many rows, rendering them all is cheap enough.
+pub const VIRTUALIZE_THRESHOLD: usize = 100;
+
+// Rows rendered beyond each edge of the viewport, so arrow-key navigation and
+// fast scrolling rarely reach a row that isn't in the DOM yet.
+const OVERSCAN: usize = 5;
+// Used until the viewport has been laid out and measured.
+const DEFAULT_VIEWPORT_HEIGHT: f64 = 800.0;
+
+// Renders only the rows inside (or near) the scrollable viewport. Rows start
+// at `estimated_row_height` and are re-measured once rendered, so cards of any
+// height line up; the spacer keeps the scrollbar proportional to the whole list.
+#[component]
+pub fn VirtualList<T, F, IV>(items: Vec<T>, estimated_row_height: f64, row: F) -> impl IntoView
+where
+    T: Clone + 'static,
+    F: Fn(T) -> IV + Clone + 'static,
+    IV: IntoView,
+{
+    let count = items.len();
+    let items = store_value(items);
+    let viewport = create_node_ref::<html::Div>();
+    let heights = create_rw_signal(vec![estimated_row_height; count]);
+    let (scroll_top, set_scroll_top) = create_signal(0.0);
+    let (viewport_height, set_viewport_height) = create_signal(DEFAULT_VIEWPORT_HEIGHT);
+
+    // offsets[i] is where row i starts; the last entry is the total height.
+    let offsets = create_memo(move |_| {
+        heights.with(|heights| {
+            let mut top = 0.0;
+            std::iter::once(0.0)
+                .chain(heights.iter().map(|h| {
+                    top += h;
+                    top
+                }))
+                .collect::<Vec<f64>>()
+        })
+    });
+    let window = create_memo(move |_| {
+        offsets.with(|offsets| {
+            let top = scroll_top.get();
+            let bottom = top + viewport_height.get();
+            let first = offsets.partition_point(|&o| o <= top).saturating_sub(1);
+            let last = offsets.partition_point(|&o| o < bottom).min(count);
+            (first.saturating_sub(OVERSCAN), (last + OVERSCAN).min(count))
+        })
+    });
+
+    let measure = move || {
+        let Some(viewport) = viewport.get_untracked() else { return };
+        set_viewport_height.set(viewport.client_height() as f64);
+        let Ok(rows) = viewport.query_selector_all(".virtual-row") else { return };
+        let measured: Vec<(usize, f64)> = (0..rows.length())
+            .filter_map(|n| rows.item(n)?.dyn_into::<web_sys::HtmlElement>().ok())
+            .filter_map(|row| {
+                let index = row.get_attribute("data-index")?.parse().ok()?;
+                Some((index, row.offset_height() as f64))
+            })
+            .collect();
+        // Only write when something moved, or measuring would re-trigger itself.
+        let changed = heights.with_untracked(|heights| {
+            measured.iter().any(|&(i, h)| heights.get(i).is_some_and(|old| (old - h).abs() > 0.5))
+        });
+        if changed {
+            heights.update(|heights| {
+                for (i, h) in measured {
+                    if let Some(old) = heights.get_mut(i) {
+                        *old = h;
+                    }
+                }
+            });
+        }
+    };
+    // Measure after the browser has laid out the newly rendered rows.
+    create_effect(move |_| {
+        window.track();
+        request_animation_frame(measure);
+    });
+
+    // `ResultList` moves focus among the cards in the DOM, which here are only
+    // the rendered rows. Keys that would leave them scroll the target row into
+    // the window first and focus its card once it has rendered.
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let Some(viewport) = viewport.get_untracked() else { return };
+        let Ok(cards) = viewport.query_selector_all(".result-item") else { return };
+        let active = document()
+            .active_element()
+            .and_then(|el| el.closest(".result-item").ok().flatten());
+        let position = active.as_ref().and_then(|active| {
+            let active: &web_sys::Node = active.as_ref();
+            (0..cards.length()).find(|&n| cards.item(n).as_ref() == Some(active))
+        });
+        let row = active
+            .and_then(|card| card.closest(".virtual-row").ok().flatten())
+            .and_then(|row| row.get_attribute("data-index")?.parse::<usize>().ok());
+
+        // The row to show, and whether to focus its first card (or its last).
+        let (target, first) = match (ev.key().as_str(), row) {
+            ("Home", _) => (0, true),
+            ("End", _) if count > 0 => (count - 1, false),
+            ("ArrowDown", Some(row)) if position.is_some_and(|p| p + 1 == cards.length()) && row + 1 < count => {
+                (row + 1, true)
+            }
+            ("ArrowUp", Some(row)) if position == Some(0) && row > 0 => (row - 1, false),
+            _ => return,
+        };
+        ev.prevent_default();
+        ev.stop_propagation();
+
+        viewport.set_scroll_top(offsets.with_untracked(|o| o[target]) as i32);
+        set_scroll_top.set(viewport.scroll_top() as f64);
+        request_animation_frame(move || {
+            let selector = format!(".virtual-row[data-index='{}'] .result-item", target);
+            let Ok(cards) = viewport.query_selector_all(&selector) else { return };
+            let n = if first { 0 } else { cards.length().saturating_sub(1) };
+            if let Some(card) = cards.item(n).and_then(|c| c.dyn_into::<web_sys::HtmlElement>().ok()) {
+                let _ = card.focus();
+            }
+        });
+    };
+
+    let rows = move || {
+        let (start, end) = window.get();
+        items.with_value(|items| (start..end).map(|i| (i, items[i].clone())).collect::<Vec<_>>())
+    };
+
+    view! {
+        <div
+            class="virtual-list"
+            node_ref=viewport
+            on:keydown=on_keydown
+            on:scroll=move |_| {
+                if let Some(viewport) = viewport.get_untracked() {
+                    set_scroll_top.set(viewport.scroll_top() as f64);
+                }
+                // Rows can grow in place (an expanded product group) without
+                // the window moving, so scrolling re-measures as well.
+                request_animation_frame(measure);
+            }
+        >
+            <div
+                class="virtual-spacer"
+                style=move || format!("height: {}px", offsets.with(|o| o[count]))
+            >
+                <div
+                    class="virtual-window"
+                    style=move || format!("transform: translateY({}px)", offsets.with(|o| o[window.get().0]))
+                >
+                    <For
+                        each=rows
+                        key=|(i, _)| *i
+                        children=move |(i, item)| {
+                            let row = row.clone();
+                            view! {
+                                <div
+                                    class="virtual-row"
+                                    role="listitem"
+                                    aria-posinset=i + 1
+                                    aria-setsize=count
+                                    data-index=i
+                                >
+                                    {row(item)}
+                                </div>
+                            }
+                        }
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}